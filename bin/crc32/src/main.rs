@@ -0,0 +1,524 @@
+use std::{
+    ffi::OsString,
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use byond_crc32::Crc32;
+
+const USAGE: &str = "\
+Usage: byond-crc32 [OPTION]... [FILE]...
+  or:  byond-crc32 [OPTION]... --combine CRC_A CRC_B LEN_B
+  or:  byond-crc32 [OPTION]... -c [MANIFEST]...
+
+Print the CRC-32/BYOND checksum and byte count of each FILE, as
+`crc len name` lines. With no FILE, or when FILE is -, read standard input.
+
+Options:
+      --hex           print and parse checksums as hexadecimal (default)
+      --dec           print and parse checksums as decimal
+      --combine CRC_A CRC_B LEN_B
+                      print the checksum of A || B, given the checksums of
+                      A and B and the length of B
+  -c, --check         verify the files listed in each MANIFEST, as printed
+                      by this tool, and exit with status 1 on any mismatch
+  -h, --help          print this help and exit
+  -V, --version       print version information and exit
+
+Checksums prefixed with 0x are always parsed as hexadecimal.
+";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Radix {
+    Hex,
+    Dec,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Mode {
+    Checksum,
+    Combine(String, String, String),
+    Check,
+    Help,
+    Version,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct Options {
+    mode: Mode,
+    radix: Radix,
+    files: Vec<PathBuf>,
+}
+
+fn parse_args<I: IntoIterator<Item = OsString>>(args: I) -> Result<Options, String> {
+    let mut args = args.into_iter();
+    let mut options = Options {
+        mode: Mode::Checksum,
+        radix: Radix::Hex,
+        files: Vec::new(),
+    };
+    while let Some(arg) = args.next() {
+        match arg.to_str() {
+            Some("--") => {
+                options.files.extend(args.by_ref().map(PathBuf::from));
+            }
+            Some("--hex") => options.radix = Radix::Hex,
+            Some("--dec") => options.radix = Radix::Dec,
+            Some("-c" | "--check") => set_mode(&mut options, Mode::Check)?,
+            Some("--combine") => {
+                let mut operand = || {
+                    args.next()
+                        .and_then(|arg| arg.into_string().ok())
+                        .ok_or_else(|| "--combine requires CRC_A CRC_B LEN_B".to_owned())
+                };
+                let mode = Mode::Combine(operand()?, operand()?, operand()?);
+                set_mode(&mut options, mode)?;
+            }
+            Some("-h" | "--help") => {
+                return Ok(Options {
+                    mode: Mode::Help,
+                    ..options
+                })
+            }
+            Some("-V" | "--version") => {
+                return Ok(Options {
+                    mode: Mode::Version,
+                    ..options
+                })
+            }
+            Some(option) if option.starts_with('-') && option != "-" => {
+                return Err(format!("unrecognized option '{}'", option));
+            }
+            _ => options.files.push(PathBuf::from(arg)),
+        }
+    }
+
+    if matches!(options.mode, Mode::Combine(..)) && !options.files.is_empty() {
+        return Err("--combine takes no files".to_owned());
+    }
+    Ok(options)
+}
+
+fn set_mode(options: &mut Options, mode: Mode) -> Result<(), String> {
+    if options.mode != Mode::Checksum {
+        return Err("--combine and --check are mutually exclusive".to_owned());
+    }
+    options.mode = mode;
+    Ok(())
+}
+
+fn format_crc(crc: u32, radix: Radix) -> String {
+    match radix {
+        Radix::Hex => format!("{:08x}", crc),
+        Radix::Dec => crc.to_string(),
+    }
+}
+
+fn parse_crc(s: &str, radix: Radix) -> Result<u32, String> {
+    let parsed = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => match radix {
+            Radix::Hex => u32::from_str_radix(s, 16),
+            Radix::Dec => s.parse(),
+        },
+    };
+    parsed.map_err(|_| format!("invalid checksum '{}'", s))
+}
+
+fn parse_len(s: &str) -> Result<u64, String> {
+    s.parse().map_err(|_| format!("invalid length '{}'", s))
+}
+
+fn is_stdin(path: &Path) -> bool {
+    path.as_os_str() == "-"
+}
+
+/// Checksums the file at `path`, or standard input for `-`.
+fn checksum(path: &Path) -> io::Result<Crc32> {
+    if is_stdin(path) {
+        let mut crc32 = Crc32::new();
+        let mut stdin = io::stdin().lock();
+        loop {
+            let buf = stdin.fill_buf()?;
+            if buf.is_empty() {
+                return Ok(crc32);
+            }
+            crc32.update(buf);
+            let len = buf.len();
+            stdin.consume(len);
+        }
+    } else {
+        checksum_file(path)
+    }
+}
+
+/// Checksums the file at `path`, memory-mapping it if possible.
+fn checksum_file(path: impl AsRef<Path>) -> io::Result<Crc32> {
+    // SAFETY: like other checksum tools, this assumes files aren't modified
+    // or truncated while they're checksummed. If one is, the process may be
+    // killed by SIGBUS.
+    unsafe { byond_crc32::checksum_file(path) }
+}
+
+/// Opens the file at `path` for reading lines, or standard input for `-`.
+fn open_lines(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    if is_stdin(path) {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        Ok(Box::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+fn run_checksum(options: &Options, out: &mut impl Write, err: &mut impl Write) -> io::Result<bool> {
+    let mut ok = true;
+    if options.files.is_empty() {
+        // Like `cksum`, standard input is unnamed unless given as `-`.
+        let crc32 = checksum(Path::new("-"))?;
+        writeln!(
+            out,
+            "{} {}",
+            format_crc(crc32.as_u32(), options.radix),
+            crc32.len()
+        )?;
+    }
+    for path in &options.files {
+        match checksum(path) {
+            Ok(crc32) => writeln!(
+                out,
+                "{} {} {}",
+                format_crc(crc32.as_u32(), options.radix),
+                crc32.len(),
+                path.display()
+            )?,
+            Err(e) => {
+                writeln!(err, "byond-crc32: {}: {}", path.display(), e)?;
+                ok = false;
+            }
+        }
+    }
+    Ok(ok)
+}
+
+fn run_combine(
+    (crc_a, crc_b, len_b): (&str, &str, &str),
+    radix: Radix,
+    out: &mut impl Write,
+) -> Result<(), String> {
+    let a = Crc32::new_with_initial(parse_crc(crc_a, radix)?, 0);
+    let b = Crc32::new_with_initial(parse_crc(crc_b, radix)?, parse_len(len_b)?);
+    let crc = Crc32::combine(&a, &b).as_u32();
+    writeln!(out, "{}", format_crc(crc, radix)).map_err(|e| e.to_string())
+}
+
+/// Parses a manifest line into its checksum, length and file name.
+fn parse_manifest_line(line: &str, radix: Radix) -> Option<(u32, u64, &str)> {
+    let mut parts = line.splitn(3, ' ');
+    let crc = parse_crc(parts.next()?, radix).ok()?;
+    let len = parse_len(parts.next()?).ok()?;
+    let name = parts.next().filter(|name| !name.is_empty())?;
+    Some((crc, len, name))
+}
+
+fn run_check(options: &Options, out: &mut impl Write, err: &mut impl Write) -> io::Result<bool> {
+    let stdin = [PathBuf::from("-")];
+    let manifests = if options.files.is_empty() {
+        &stdin[..]
+    } else {
+        &options.files[..]
+    };
+
+    let mut ok = true;
+    for manifest in manifests {
+        let lines = match open_lines(manifest) {
+            Ok(lines) => lines,
+            Err(e) => {
+                writeln!(err, "byond-crc32: {}: {}", manifest.display(), e)?;
+                ok = false;
+                continue;
+            }
+        };
+
+        let (mut checked, mut malformed, mut unreadable, mut mismatched) = (0, 0, 0, 0);
+        for line in lines.lines() {
+            let line = line?;
+            let line = line.trim_end_matches('\r');
+            if line.is_empty() {
+                continue;
+            }
+            let (crc, len, name) = match parse_manifest_line(line, options.radix) {
+                Some(entry) => entry,
+                None => {
+                    malformed += 1;
+                    continue;
+                }
+            };
+            checked += 1;
+            // Listed names are always paths, even `-`.
+            match checksum_file(name) {
+                Ok(crc32) if crc32.as_u32() == crc && crc32.len() == len => {
+                    writeln!(out, "{}: OK", name)?;
+                }
+                Ok(_) => {
+                    writeln!(out, "{}: FAILED", name)?;
+                    mismatched += 1;
+                }
+                Err(e) => {
+                    writeln!(err, "byond-crc32: {}: {}", name, e)?;
+                    writeln!(out, "{}: FAILED open or read", name)?;
+                    unreadable += 1;
+                }
+            }
+        }
+
+        if malformed > 0 {
+            let line = plural(malformed, "line is", "lines are");
+            writeln!(
+                err,
+                "byond-crc32: WARNING: {} {} improperly formatted",
+                malformed, line
+            )?;
+        }
+        if unreadable > 0 {
+            let file = plural(unreadable, "file", "files");
+            writeln!(
+                err,
+                "byond-crc32: WARNING: {} listed {} could not be read",
+                unreadable, file
+            )?;
+        }
+        if mismatched > 0 {
+            let checksum = plural(mismatched, "checksum", "checksums");
+            writeln!(
+                err,
+                "byond-crc32: WARNING: {} computed {} did NOT match",
+                mismatched, checksum
+            )?;
+        }
+        if checked == 0 {
+            writeln!(
+                err,
+                "byond-crc32: {}: no properly formatted checksum lines found",
+                manifest.display()
+            )?;
+        }
+        ok &= checked > 0 && unreadable == 0 && mismatched == 0;
+    }
+    Ok(ok)
+}
+
+fn plural<'a>(count: usize, one: &'a str, many: &'a str) -> &'a str {
+    if count == 1 {
+        one
+    } else {
+        many
+    }
+}
+
+fn run<I: IntoIterator<Item = OsString>>(
+    args: I,
+    out: &mut impl Write,
+    err: &mut impl Write,
+) -> ExitCode {
+    let options = match parse_args(args) {
+        Ok(options) => options,
+        Err(e) => {
+            let _ = writeln!(err, "byond-crc32: {}", e);
+            let _ = writeln!(err, "Try 'byond-crc32 --help' for more information.");
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match &options.mode {
+        Mode::Help => out.write_all(USAGE.as_bytes()).map(|_| true),
+        Mode::Version => writeln!(out, "byond-crc32 {}", env!("CARGO_PKG_VERSION")).map(|_| true),
+        Mode::Checksum => run_checksum(&options, out, err),
+        Mode::Check => run_check(&options, out, err),
+        Mode::Combine(crc_a, crc_b, len_b) => {
+            match run_combine((crc_a, crc_b, len_b), options.radix, out) {
+                Ok(()) => Ok(true),
+                Err(e) => {
+                    let _ = writeln!(err, "byond-crc32: {}", e);
+                    return ExitCode::from(2);
+                }
+            }
+        }
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            let _ = writeln!(err, "byond-crc32: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let mut out = io::stdout().lock();
+    let mut err = io::stderr().lock();
+    run(std::env::args_os().skip(1), &mut out, &mut err)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf, process::ExitCode};
+
+    use super::{parse_args, parse_crc, parse_manifest_line, Mode, Radix};
+
+    /// A directory in the temporary directory, removed on drop.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "byond-crc32-cli-{}-{}",
+                name,
+                std::process::id()
+            ));
+            fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+
+        fn file(&self, name: &str, contents: &[u8]) -> String {
+            let path = self.0.join(name);
+            fs::write(&path, contents).unwrap();
+            path.to_str().unwrap().to_owned()
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn run(args: &[&str]) -> (ExitCode, String, String) {
+        let (mut out, mut err) = (Vec::new(), Vec::new());
+        let code = super::run(args.iter().map(Into::into), &mut out, &mut err);
+        (
+            code,
+            String::from_utf8(out).unwrap(),
+            String::from_utf8(err).unwrap(),
+        )
+    }
+
+    #[test]
+    fn parses_args() {
+        let options = parse_args(["--dec", "a", "-", "--", "--hex"].map(Into::into)).unwrap();
+        assert_eq!(Mode::Checksum, options.mode);
+        assert_eq!(Radix::Dec, options.radix);
+        assert_eq!(
+            vec![
+                PathBuf::from("a"),
+                PathBuf::from("-"),
+                PathBuf::from("--hex")
+            ],
+            options.files
+        );
+
+        assert!(parse_args(["--bogus"].map(Into::into)).is_err());
+        assert!(parse_args(["--combine", "1", "2"].map(Into::into)).is_err());
+        assert!(parse_args(["-c", "--combine", "1", "2", "3"].map(Into::into)).is_err());
+    }
+
+    #[test]
+    fn parses_crcs() {
+        assert_eq!(Ok(0xa5fd3138), parse_crc("a5fd3138", Radix::Hex));
+        assert_eq!(Ok(0xa5fd3138), parse_crc("0xa5fd3138", Radix::Dec));
+        assert_eq!(Ok(2784833848), parse_crc("2784833848", Radix::Dec));
+        assert!(parse_crc("a5fd3138", Radix::Dec).is_err());
+        assert_eq!(
+            Some((0xa5fd3138, 9, "file name.txt")),
+            parse_manifest_line("a5fd3138 9 file name.txt", Radix::Hex)
+        );
+        assert_eq!(None, parse_manifest_line("a5fd3138 9", Radix::Hex));
+    }
+
+    #[test]
+    fn checksums_files() {
+        let dir = TempDir::new("checksum");
+        let check = dir.file("check", b"123456789");
+        let empty = dir.file("empty", b"");
+
+        let (code, out, _) = run(&[&check, &empty]);
+        assert_eq!(ExitCode::SUCCESS, code);
+        assert_eq!(format!("a5fd3138 9 {}\nffffffff 0 {}\n", check, empty), out);
+
+        let (code, out, _) = run(&["--dec", &check]);
+        assert_eq!(ExitCode::SUCCESS, code);
+        assert_eq!(format!("2784833848 9 {}\n", check), out);
+
+        let missing = dir.0.join("missing");
+        let (code, _, err) = run(&[missing.to_str().unwrap()]);
+        assert_eq!(ExitCode::FAILURE, code);
+        assert!(err.contains("missing"));
+    }
+
+    #[test]
+    fn combines() {
+        let checksum = |data: &[u8]| {
+            let mut crc32 = byond_crc32::Crc32::new();
+            crc32.update(data);
+            crc32.as_u32()
+        };
+        let crc_a = format!("{:08x}", checksum(b"12345"));
+        let crc_b = format!("{:#x}", checksum(b"6789"));
+        let (code, out, _) = run(&["--combine", &crc_a, &crc_b, "4"]);
+        assert_eq!(ExitCode::SUCCESS, code);
+        assert_eq!("a5fd3138\n", out);
+
+        let crc_a = checksum(b"12345").to_string();
+        let crc_b = checksum(b"6789").to_string();
+        let (code, out, _) = run(&["--dec", "--combine", &crc_a, &crc_b, "4"]);
+        assert_eq!(ExitCode::SUCCESS, code);
+        assert_eq!("2784833848\n", out);
+
+        let (code, _, _) = run(&["--combine", "xyz", &crc_b, "4"]);
+        assert_eq!(ExitCode::from(2), code);
+    }
+
+    #[test]
+    fn checks_manifests() {
+        let dir = TempDir::new("check");
+        let good = dir.file("good", b"123456789");
+        let bad = dir.file("bad", b"12345678");
+        let manifest = dir.file(
+            "manifest",
+            format!("a5fd3138 9 {}\na5fd3138 9 {}\n", good, bad).as_bytes(),
+        );
+
+        let (code, out, err) = run(&["-c", &manifest]);
+        assert_eq!(ExitCode::FAILURE, code);
+        assert_eq!(format!("{}: OK\n{}: FAILED\n", good, bad), out);
+        assert!(err.contains("1 computed checksum did NOT match"));
+
+        let manifest = dir.file("manifest", format!("a5fd3138 9 {}\n", good).as_bytes());
+        let (code, out, _) = run(&["--check", &manifest]);
+        assert_eq!(ExitCode::SUCCESS, code);
+        assert_eq!(format!("{}: OK\n", good), out);
+
+        let manifest = dir.file("manifest", b"not a checksum\n");
+        let (code, _, err) = run(&["-c", &manifest]);
+        assert_eq!(ExitCode::FAILURE, code);
+        assert!(err.contains("no properly formatted checksum lines found"));
+    }
+
+    #[test]
+    fn round_trips_manifests() {
+        let dir = TempDir::new("round-trip");
+        let files: Vec<_> = (0..4)
+            .map(|i| dir.file(&format!("file {}", i), &vec![i as u8; i * 1000]))
+            .collect();
+        let args: Vec<_> = files.iter().map(String::as_str).collect();
+        let (code, manifest, _) = run(&args);
+        assert_eq!(ExitCode::SUCCESS, code);
+
+        let manifest = dir.file("manifest", manifest.as_bytes());
+        let (code, out, _) = run(&["-c", &manifest]);
+        assert_eq!(ExitCode::SUCCESS, code);
+        assert_eq!(4, out.matches(": OK\n").count());
+    }
+}
@@ -1,5 +1,10 @@
-use byond_crc32::{baseline, specialized};
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::{
+    collections::{hash_map::RandomState, HashMap},
+    hash::BuildHasher,
+};
+
+use byond_crc32::{baseline, specialized, BuildCrc32Hasher};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 
 fn crc32_4kib_baseline(c: &mut Criterion) {
     c.bench_function("CRC32/BYOND 4KiB Baseline", |b| {
@@ -76,6 +81,88 @@ fn crc32_4gib_specialized(c: &mut Criterion) {
     });
 }
 
+const SHORT_SIZES: [usize; 14] = [1, 3, 4, 8, 15, 16, 17, 31, 32, 64, 128, 255, 256, 512];
+
+fn crc32_short_baseline(c: &mut Criterion) {
+    let mut group = c.benchmark_group("CRC32/BYOND Short Baseline");
+    let state = baseline::State::new(0xffffffff);
+    for size in SHORT_SIZES {
+        let v = vec![13u8; size];
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &v, |b, v| {
+            b.iter(|| {
+                let mut crc = state;
+                crc.update(black_box(v.as_slice()));
+                crc.as_u32()
+            });
+        });
+    }
+    group.finish();
+}
+
+fn crc32_short_specialized(c: &mut Criterion) {
+    if specialized::State::new(0xffffffff).is_none() {
+        return;
+    }
+    let mut group = c.benchmark_group("CRC32/BYOND Short Specialized");
+    // Detect the backend up front, so short inputs don't just measure it.
+    let state = specialized::State::new(0xffffffff).unwrap();
+    for size in SHORT_SIZES {
+        let v = vec![13u8; size];
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &v, |b, v| {
+            b.iter(|| {
+                let mut crc = state;
+                crc.update(black_box(v.as_slice()));
+                crc.as_u32()
+            });
+        });
+    }
+    group.finish();
+}
+
+const KEY_SIZES: [usize; 5] = [4, 8, 16, 32, 64];
+
+/// Generates 1024 distinct keys of the given length, like resource paths.
+fn keys(size: usize) -> Vec<String> {
+    (0..1024)
+        .map(|i| {
+            let mut key = "icons/".repeat(size / 6 + 1);
+            key.truncate(size - 4);
+            key + &format!("{:04}", i)
+        })
+        .collect()
+}
+
+fn lookup<S: BuildHasher + Default>(c: &mut Criterion, name: &str) {
+    let mut group = c.benchmark_group(format!("HashMap Lookup {}", name));
+    for size in KEY_SIZES {
+        let keys = keys(size);
+        let map: HashMap<_, _, S> = keys
+            .iter()
+            .enumerate()
+            .map(|(i, k)| (k.clone(), i))
+            .collect();
+        group.throughput(Throughput::Elements(keys.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &keys, |b, keys| {
+            b.iter(|| {
+                keys.iter()
+                    .map(|k| map[black_box(k.as_str())])
+                    .sum::<usize>()
+            });
+        });
+    }
+    group.finish();
+}
+
+fn lookup_crc32(c: &mut Criterion) {
+    lookup::<BuildCrc32Hasher>(c, "CRC32/BYOND");
+}
+
+fn lookup_siphash(c: &mut Criterion) {
+    lookup::<RandomState>(c, "SipHash");
+}
+
 fn naive_crc32(crc: u32, bytes: &[u8]) -> u32 {
     bytes.iter().fold(crc, |crc, &byte| {
         (crc << 8) ^ BYTE_TABLE[(crc >> 24) as usize ^ byte as usize]
@@ -118,7 +205,15 @@ criterion_group! {
     config = Criterion::default().sample_size(10);
     targets = crc32_4kib_specialized, crc32_4mib_specialized, crc32_4gib_specialized
 }
-criterion_main!(naive_benches, baseline_benches, specialized_benches);
+criterion_group!(short_benches, crc32_short_baseline, crc32_short_specialized);
+criterion_group!(hash_benches, lookup_crc32, lookup_siphash);
+criterion_main!(
+    naive_benches,
+    baseline_benches,
+    specialized_benches,
+    short_benches,
+    hash_benches
+);
 
 const BYTE_TABLE: [u32; 256] = [
     0x0000, 0x00af, 0x015e, 0x01f1, 0x02bc, 0x0213, 0x03e2, 0x034d, 0x0578, 0x05d7, 0x0426, 0x0489,
@@ -1,5 +1,6 @@
 use crate::{
-    tables::{BYTE_TABLE, WORD_TABLE},
+    engine::{Byond, Tables},
+    tables::{BYTE_TABLE, BYTE_TABLE_INVERSE},
     DEFAULT_CRC32,
 };
 
@@ -27,37 +28,72 @@ impl State {
 }
 
 #[inline(always)]
-pub(crate) fn slice_by_16(mut crc: u32, bytes: &[u8]) -> u32 {
-    crc = u32::swap_bytes(crc);
+pub(crate) fn slice_by_16(crc: u32, bytes: &[u8]) -> u32 {
+    slice_by_16_with::<Byond>(crc, bytes)
+}
+
+/// Slice-by-16 with the tables for the CRC parameters `P`.
+#[inline(always)]
+pub(crate) fn slice_by_16_with<P: Tables>(mut crc: u32, bytes: &[u8]) -> u32 {
+    let table = &P::WORD_TABLE;
+    if !P::REFLECTED {
+        crc = u32::swap_bytes(crc);
+    }
     let chunks = bytes.chunks_exact(16);
     let remainder = chunks.remainder();
     crc = chunks.fold(crc, |mut crc, word| {
         crc ^= u32::from_le_bytes(word[0..4].try_into().unwrap());
-        WORD_TABLE[15][(crc & 0xff) as usize]
-            ^ WORD_TABLE[14][((crc >> 8) & 0xff) as usize]
-            ^ WORD_TABLE[13][((crc >> 16) & 0xff) as usize]
-            ^ WORD_TABLE[12][(crc >> 24) as usize]
-            ^ WORD_TABLE[11][word[4] as usize]
-            ^ WORD_TABLE[10][word[5] as usize]
-            ^ WORD_TABLE[9][word[6] as usize]
-            ^ WORD_TABLE[8][word[7] as usize]
-            ^ WORD_TABLE[7][word[8] as usize]
-            ^ WORD_TABLE[6][word[9] as usize]
-            ^ WORD_TABLE[5][word[10] as usize]
-            ^ WORD_TABLE[4][word[11] as usize]
-            ^ WORD_TABLE[3][word[12] as usize]
-            ^ WORD_TABLE[2][word[13] as usize]
-            ^ WORD_TABLE[1][word[14] as usize]
-            ^ WORD_TABLE[0][word[15] as usize]
+        table[15][(crc & 0xff) as usize]
+            ^ table[14][((crc >> 8) & 0xff) as usize]
+            ^ table[13][((crc >> 16) & 0xff) as usize]
+            ^ table[12][(crc >> 24) as usize]
+            ^ table[11][word[4] as usize]
+            ^ table[10][word[5] as usize]
+            ^ table[9][word[6] as usize]
+            ^ table[8][word[7] as usize]
+            ^ table[7][word[8] as usize]
+            ^ table[6][word[9] as usize]
+            ^ table[5][word[10] as usize]
+            ^ table[4][word[11] as usize]
+            ^ table[3][word[12] as usize]
+            ^ table[2][word[13] as usize]
+            ^ table[1][word[14] as usize]
+            ^ table[0][word[15] as usize]
     });
-    crc = u32::swap_bytes(crc);
-    slice_by_1(crc, remainder)
+    if !P::REFLECTED {
+        crc = u32::swap_bytes(crc);
+    }
+    slice_by_1_with::<P>(crc, remainder)
+}
+
+#[inline(always)]
+pub(crate) const fn slice_by_1(crc: u32, bytes: &[u8]) -> u32 {
+    slice_by_1_with::<Byond>(crc, bytes)
 }
 
+/// Slice-by-1 with the table for the CRC parameters `P`.
 #[inline(always)]
-pub(crate) fn slice_by_1(crc: u32, bytes: &[u8]) -> u32 {
-    bytes.iter().fold(crc, |crc, &byte| {
-        (crc << 8) ^ BYTE_TABLE[(crc >> 24) as usize ^ byte as usize]
+pub(crate) const fn slice_by_1_with<P: Tables>(mut crc: u32, bytes: &[u8]) -> u32 {
+    // A `while` loop rather than an iterator, so this can run at compile time.
+    let mut i = 0;
+    while i < bytes.len() {
+        crc = if P::REFLECTED {
+            (crc >> 8) ^ P::BYTE_TABLE[(crc & 0xff) as usize ^ bytes[i] as usize]
+        } else {
+            (crc << 8) ^ P::BYTE_TABLE[(crc >> 24) as usize ^ bytes[i] as usize]
+        };
+        i += 1;
+    }
+    crc
+}
+
+/// Reverses [`slice_by_1`], removing `bytes` from the end of the data
+/// consumed to create `crc`.
+#[inline(always)]
+pub(crate) fn unslice_by_1(crc: u32, bytes: &[u8]) -> u32 {
+    bytes.iter().rev().fold(crc, |crc, &byte| {
+        let index = BYTE_TABLE_INVERSE[(crc & 0xff) as usize];
+        ((crc ^ BYTE_TABLE[index as usize]) >> 8) | (u32::from(index ^ byte) << 24)
     })
 }
 
@@ -75,4 +111,9 @@ mod tests {
     fn slice_by_1_matches_golden(crc: u32, bytes: Vec<u8>) -> bool {
         super::slice_by_1(crc, &bytes) == golden(crc, &bytes)
     }
+
+    #[quickcheck]
+    fn unslice_by_1_reverses_slice_by_1(crc: u32, bytes: Vec<u8>) -> bool {
+        super::unslice_by_1(super::slice_by_1(crc, &bytes), &bytes) == crc
+    }
 }
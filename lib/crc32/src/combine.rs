@@ -1,29 +1,153 @@
-const COMBINE_TABLE: [u32; 32] = [
-    0x00000100, 0x00010000, 0x000000af, 0x00004455, 0x10101111, 0xae01ae01, 0x1bd81099, 0x87f2f581,
-    0x9dd170d4, 0x5bbedfd6, 0x27afa5be, 0xf0db1b29, 0x2264a683, 0xfaa007ab, 0x0a402c54, 0x2d26e110,
-    0xc99cb412, 0x5e545a7b, 0x6dc24493, 0x7dae76c1, 0xd7a20af5, 0x978e3ee2, 0x629ca6e1, 0x0a831f49,
-    0x2802d252, 0xc6c412e6, 0x39697bab, 0x2d222274, 0x9999aaf2, 0x00000002, 0x00000004, 0x00000010,
-];
+use crate::{specialized, tables::POLY, Crc32};
+
+/// x^(8 * 2^k) modulo P(x) for `COMBINE_TABLE[k]`. These cycle after 32
+/// entries, as x^(8 * 2^32) is x^8 again.
+const COMBINE_TABLE: [u32; 32] = combine_table();
+
+/// x^-8 modulo P(x), the multiplicative inverse of `COMBINE_TABLE[0]`.
+const X8_INVERSE: u32 = x8_inverse();
+
+const fn combine_table() -> [u32; 32] {
+    let mut table = [0; 32];
+    table[0] = 1 << 8;
+    let mut k = 1;
+    while k < 32 {
+        table[k] = multmodp(table[k - 1], table[k - 1]);
+        k += 1;
+    }
+    table
+}
+
+const fn x8_inverse() -> u32 {
+    // x * (P(x) - 1) / x = P(x) - 1, which is 1 modulo P(x).
+    let x_inverse = 1 << 31 | POLY >> 1;
+    let x2_inverse = multmodp(x_inverse, x_inverse);
+    let x4_inverse = multmodp(x2_inverse, x2_inverse);
+    multmodp(x4_inverse, x4_inverse)
+}
 
 #[inline(always)]
-pub(crate) fn combine(mut crc1: u32, crc2: u32, len2: u64) -> u32 {
+pub(crate) fn combine(crc1: u32, crc2: u32, len2: u64) -> u32 {
+    combine_shifted(crc1, crc2, x8nmodp(len2))
+}
+
+/// [`combine`], given x^(8 * len2) modulo P(x) for the length of the second
+/// checksum.
+#[inline(always)]
+fn combine_shifted(mut crc1: u32, crc2: u32, shift: u32) -> u32 {
     crc1 ^= 0xffffffff;
-    multmodp(x8nmodp(len2), crc1) ^ crc2
+    mul(shift, crc1) ^ crc2
+}
+
+/// Combines CRC-32/BYOND checksums with the checksum of a fixed length of
+/// data following them.
+///
+/// [`Crc32::combine`] computes an operator for the second checksum's length
+/// each time. This computes it once, which is faster when combining many
+/// checksums of equal length, such as fixed-size blocks.
+///
+/// ## Example
+///
+/// ```rust
+/// use byond_crc32::{Combiner, Crc32};
+///
+/// let combiner = Combiner::for_len(4);
+/// let mut crc32 = Crc32::new();
+/// for block in [b"0123", b"4567", b"89ab"] {
+///     let mut block_crc32 = Crc32::new();
+///     block_crc32.update(block);
+///     crc32 = combiner.combine(&crc32, &block_crc32);
+/// }
+///
+/// let mut expected = Crc32::new();
+/// expected.update(b"0123456789ab");
+/// assert_eq!(expected, crc32);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Combiner {
+    len: u64,
+    shift: u32,
 }
 
+impl Combiner {
+    /// Creates a combiner for second checksums over `len` bytes.
+    pub fn for_len(len: u64) -> Self {
+        Self {
+            len,
+            shift: x8nmodp(len),
+        }
+    }
+
+    /// The length of data the second checksums must be over.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns true if this combiner is for empty second checksums.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Combines two CRC-32/BYOND checksums, like [`Crc32::combine`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` is not over [`Combiner::len`] bytes.
+    pub fn combine(&self, a: &Crc32, b: &Crc32) -> Crc32 {
+        assert_eq!(self.len, b.len(), "combined checksum has the wrong length");
+        Crc32::new_with_initial(self.combine_u32(a.as_u32(), b.as_u32()), a.len() + b.len())
+    }
+
+    /// Combines two CRC-32/BYOND checksum values, where `crc2` is over
+    /// [`Combiner::len`] bytes.
+    pub fn combine_u32(&self, crc1: u32, crc2: u32) -> u32 {
+        combine_shifted(crc1, crc2, self.shift)
+    }
+}
+
+/// Given the checksums of `A || B` and `A`, gets the checksum of `B`.
 #[inline(always)]
-fn multmodp(mut a: u32, mut b: u32) -> u32 {
+pub(crate) fn split_suffix(crc: u32, mut crc1: u32, len2: u64) -> u32 {
+    crc1 ^= 0xffffffff;
+    mul(x8nmodp(len2), crc1) ^ crc
+}
+
+/// Given the checksums of `A || B` and `B`, gets the checksum of `A`.
+#[inline(always)]
+pub(crate) fn split_prefix(crc: u32, crc2: u32, len2: u64) -> u32 {
+    unzeros(crc ^ crc2, len2) ^ 0xffffffff
+}
+
+/// Advances `crc` as if `len` zero bytes had been consumed.
+#[inline(always)]
+pub(crate) fn zeros(crc: u32, len: u64) -> u32 {
+    mul(x8nmodp(len), crc)
+}
+
+/// Reverses [`zeros`], rewinding `crc` as if `len` zero bytes had been
+/// removed from the end of the consumed data.
+#[inline(always)]
+pub(crate) fn unzeros(crc: u32, len: u64) -> u32 {
+    mul(x8nmodp_inverse(len), crc)
+}
+
+/// a(x) * b(x) modulo P(x), with carry-less multiplication instructions if
+/// the current CPU supports them.
+#[inline(always)]
+pub(crate) fn mul(a: u32, b: u32) -> u32 {
+    specialized::multmodp(a, b).unwrap_or_else(|| multmodp(a, b))
+}
+
+#[inline(always)]
+pub(crate) const fn multmodp(mut a: u32, mut b: u32) -> u32 {
     let mut prod = 0;
-    loop {
+    while a != 0 {
         if a & 1 != 0 {
             prod ^= b;
-            if a == 1 {
-                break;
-            }
         }
         a >>= 1;
         b = if b & 0x80000000 != 0 {
-            (b << 1) ^ 0xaf
+            (b << 1) ^ POLY
         } else {
             b << 1
         };
@@ -32,12 +156,12 @@ fn multmodp(mut a: u32, mut b: u32) -> u32 {
 }
 
 #[inline(always)]
-fn x8nmodp(mut n: u64) -> u32 {
+pub(crate) fn x8nmodp(mut n: u64) -> u32 {
     let mut xp = 1;
     let mut k = 0;
     while n != 0 {
         if n & 1 != 0 {
-            xp = multmodp(COMBINE_TABLE[k], xp);
+            xp = mul(COMBINE_TABLE[k], xp);
         }
         n >>= 1;
         k += 1;
@@ -47,3 +171,54 @@ fn x8nmodp(mut n: u64) -> u32 {
     }
     xp
 }
+
+#[inline(always)]
+pub(crate) fn x8nmodp_inverse(mut n: u64) -> u32 {
+    let mut xp = 1;
+    let mut base = X8_INVERSE;
+    while n != 0 {
+        if n & 1 != 0 {
+            xp = mul(base, xp);
+        }
+        n >>= 1;
+        base = mul(base, base);
+    }
+    xp
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    #[test]
+    fn combine_table_matches_literals() {
+        #[rustfmt::skip]
+        let literals = [
+            0x00000100, 0x00010000, 0x000000af, 0x00004455, 0x10101111, 0xae01ae01, 0x1bd81099,
+            0x87f2f581, 0x9dd170d4, 0x5bbedfd6, 0x27afa5be, 0xf0db1b29, 0x2264a683, 0xfaa007ab,
+            0x0a402c54, 0x2d26e110, 0xc99cb412, 0x5e545a7b, 0x6dc24493, 0x7dae76c1, 0xd7a20af5,
+            0x978e3ee2, 0x629ca6e1, 0x0a831f49, 0x2802d252, 0xc6c412e6, 0x39697bab, 0x2d222274,
+            0x9999aaf2, 0x00000002, 0x00000004, 0x00000010,
+        ];
+        assert_eq!(literals, super::COMBINE_TABLE);
+        assert_eq!(0x1300000b, super::X8_INVERSE);
+    }
+
+    #[test]
+    fn x8_inverse_is_inverse() {
+        assert_eq!(
+            1,
+            super::multmodp(super::X8_INVERSE, super::COMBINE_TABLE[0])
+        );
+    }
+
+    #[quickcheck]
+    fn mul_matches_multmodp(a: u32, b: u32) -> bool {
+        super::mul(a, b) == super::multmodp(a, b)
+    }
+
+    #[quickcheck]
+    fn x8nmodp_inverse_is_inverse(n: u64) -> bool {
+        super::multmodp(super::x8nmodp_inverse(n), super::x8nmodp(n)) == 1
+    }
+}
@@ -0,0 +1,95 @@
+use ::digest::{
+    consts::U4, FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+use crate::Crc32;
+
+impl HashMarker for Crc32 {}
+
+impl OutputSizeUser for Crc32 {
+    type OutputSize = U4;
+}
+
+impl Update for Crc32 {
+    fn update(&mut self, data: &[u8]) {
+        Crc32::update(self, data);
+    }
+}
+
+/// The output is the checksum in big-endian byte order.
+impl FixedOutput for Crc32 {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&self.as_u32().to_be_bytes());
+    }
+}
+
+impl FixedOutputReset for Crc32 {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&self.as_u32().to_be_bytes());
+        Crc32::reset(self);
+    }
+}
+
+impl Reset for Crc32 {
+    fn reset(&mut self) {
+        Crc32::reset(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::digest::{Digest, FixedOutputReset, Reset, Update};
+    use quickcheck_macros::quickcheck;
+
+    use crate::{golden, Crc32};
+
+    fn digest<D: Digest>(chunks: &[Vec<u8>]) -> Vec<u8> {
+        let mut hasher = D::new();
+        for chunk in chunks {
+            hasher.update(chunk);
+        }
+        hasher.finalize().to_vec()
+    }
+
+    #[test]
+    fn check() {
+        assert_eq!(4, <Crc32 as Digest>::output_size());
+        assert_eq!(
+            [0xa5, 0xfd, 0x31, 0x38],
+            Crc32::digest(b"123456789").as_slice()
+        );
+    }
+
+    #[quickcheck]
+    fn digest_matches_golden(chunks: Vec<Vec<u8>>) -> bool {
+        let expected = golden(crate::DEFAULT_CRC32, &chunks.concat());
+        digest::<Crc32>(&chunks) == expected.to_be_bytes()
+    }
+
+    #[quickcheck]
+    fn reset_matches_new(a: Vec<u8>, b: Vec<u8>) -> bool {
+        fn finalize_twice<D: Update + FixedOutputReset + Reset + Default>(
+            a: &[u8],
+            b: &[u8],
+        ) -> (Vec<u8>, Vec<u8>) {
+            let mut hasher = D::default();
+            hasher.update(b"discarded");
+            Reset::reset(&mut hasher);
+            hasher.update(a);
+            let first = hasher.finalize_fixed_reset().to_vec();
+            hasher.update(b);
+            (first, hasher.finalize_fixed_reset().to_vec())
+        }
+        let (first, second) = finalize_twice::<Crc32>(&a, &b);
+        first == Crc32::digest(&a).to_vec() && second == Crc32::digest(&b).to_vec()
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn dyn_digest() {
+        let mut hasher: Box<dyn ::digest::DynDigest> = Box::new(Crc32::new());
+        hasher.update(b"12345");
+        hasher.update(b"6789");
+        assert_eq!([0xa5, 0xfd, 0x31, 0x38], *hasher.finalize());
+    }
+}
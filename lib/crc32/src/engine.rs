@@ -0,0 +1,267 @@
+#[cfg(not(feature = "std"))]
+use core::{hash::Hasher, marker::PhantomData};
+#[cfg(feature = "std")]
+use std::{hash::Hasher, marker::PhantomData};
+
+use crate::{baseline, specialized, tables};
+
+/// The parameters of a 32-bit CRC algorithm, as catalogued in the Rocksoft
+/// model.
+///
+/// Lookup tables and folding constants are generated from these at compile
+/// time, so adding a CRC algorithm only takes a new implementation.
+///
+/// ## Example
+///
+/// ```rust
+/// use byond_crc32::{Crc, CrcParams};
+///
+/// /// CRC-32C, as used by iSCSI.
+/// #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// struct Castagnoli;
+///
+/// impl CrcParams for Castagnoli {
+///     const POLY: u32 = 0x1edc6f41;
+///     const INIT: u32 = 0xffffffff;
+///     const REFLECTED: bool = true;
+///     const XOROUT: u32 = 0xffffffff;
+/// }
+///
+/// let mut crc = Crc::<Castagnoli>::new();
+/// crc.update(b"123456789");
+/// assert_eq!(0xe3069283, crc.as_u32());
+/// ```
+pub trait CrcParams {
+    /// The generator polynomial, without its x^32 term, in normal
+    /// (most significant bit first) form.
+    const POLY: u32;
+    /// The initial register value.
+    const INIT: u32;
+    /// Whether both the input bytes and the final register are bit-reflected.
+    const REFLECTED: bool;
+    /// The value XORed into the final register to get the checksum.
+    const XOROUT: u32;
+}
+
+/// The CRC-32/BYOND parameters. [`Crc32`](crate::Crc32) implements the same
+/// algorithm, with additional operations such as combining checksums.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Byond;
+
+impl CrcParams for Byond {
+    const POLY: u32 = tables::POLY;
+    const INIT: u32 = crate::DEFAULT_CRC32;
+    const REFLECTED: bool = false;
+    const XOROUT: u32 = 0;
+}
+
+/// The CRC-32/ISO-HDLC parameters, as used by PNG chunks and zlib.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Png;
+
+impl CrcParams for Png {
+    const POLY: u32 = 0x04c11db7;
+    const INIT: u32 = 0xffffffff;
+    const REFLECTED: bool = true;
+    const XOROUT: u32 = 0xffffffff;
+}
+
+/// The lookup tables for a set of CRC parameters.
+pub(crate) trait Tables: CrcParams {
+    const BYTE_TABLE: [u32; 256] = tables::byte_table(Self::POLY, Self::REFLECTED);
+    const WORD_TABLE: [[u32; 256]; 16] = tables::word_table(Self::POLY, Self::REFLECTED);
+}
+
+impl<P: CrcParams> Tables for P {}
+
+/// Represents an in-progress CRC computation with the parameters `P`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Crc<P> {
+    len: u64,
+    state: u32,
+    engine: Option<specialized::Engine>,
+    params: PhantomData<P>,
+}
+
+impl<P: CrcParams> Crc<P> {
+    /// Creates a new CRC computation hasher.
+    pub fn new() -> Self {
+        Self {
+            len: 0,
+            state: Self::init(),
+            engine: specialized::Engine::detect::<P>(),
+            params: PhantomData,
+        }
+    }
+
+    fn init() -> u32 {
+        // Reflected algorithms keep their register reflected throughout.
+        if P::REFLECTED {
+            P::INIT.reverse_bits()
+        } else {
+            P::INIT
+        }
+    }
+
+    /// Gets the checksum of the data consumed so far.
+    pub fn as_u32(&self) -> u32 {
+        self.state ^ P::XOROUT
+    }
+
+    /// Returns true if no data has been consumed so far.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The length of data consumed to create the current checksum value.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Resets the CRC computation hasher to its initial state.
+    pub fn reset(&mut self) {
+        self.len = 0;
+        self.state = Self::init();
+    }
+
+    /// Updates the CRC computation with the given `bytes`.
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.len += bytes.len() as u64;
+        self.state = match self.engine {
+            Some(engine) => engine.checksum::<P>(self.state, bytes),
+            None => baseline::slice_by_16_with::<P>(self.state, bytes),
+        };
+    }
+}
+
+impl<P: CrcParams> Default for Crc<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: CrcParams> Hasher for Crc<P> {
+    fn finish(&self) -> u64 {
+        self.as_u32() as u64
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use super::{Byond, Crc, CrcParams, Png};
+
+    /// CRC-32/MPEG-2, a non-reflected algorithm with another polynomial.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    struct Mpeg2;
+
+    impl CrcParams for Mpeg2 {
+        const POLY: u32 = 0x04c11db7;
+        const INIT: u32 = 0xffffffff;
+        const REFLECTED: bool = false;
+        const XOROUT: u32 = 0;
+    }
+
+    /// CRC-32C, a reflected algorithm with another polynomial.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    struct Castagnoli;
+
+    impl CrcParams for Castagnoli {
+        const POLY: u32 = 0x1edc6f41;
+        const INIT: u32 = 0xffffffff;
+        const REFLECTED: bool = true;
+        const XOROUT: u32 = 0xffffffff;
+    }
+
+    /// A bit-at-a-time implementation of the Rocksoft model.
+    fn golden<P: CrcParams>(bytes: &[u8]) -> u32 {
+        let crc = bytes.iter().fold(P::INIT, |mut crc, &byte| {
+            let byte = if P::REFLECTED {
+                byte.reverse_bits()
+            } else {
+                byte
+            };
+            crc ^= u32::from(byte) << 24;
+            for _ in 0..8 {
+                crc = if crc & 0x80000000 != 0 {
+                    (crc << 1) ^ P::POLY
+                } else {
+                    crc << 1
+                };
+            }
+            crc
+        });
+        let crc = if P::REFLECTED {
+            crc.reverse_bits()
+        } else {
+            crc
+        };
+        crc ^ P::XOROUT
+    }
+
+    fn checksum<P: CrcParams>(bytes: &[u8]) -> u32 {
+        let mut crc = Crc::<P>::new();
+        crc.update(bytes);
+        crc.as_u32()
+    }
+
+    #[test]
+    fn check_values() {
+        assert_eq!(0xa5fd3138, checksum::<Byond>(b"123456789"));
+        assert_eq!(0xcbf43926, checksum::<Png>(b"123456789"));
+        assert_eq!(0x0376e6e7, checksum::<Mpeg2>(b"123456789"));
+        assert_eq!(0xe3069283, checksum::<Castagnoli>(b"123456789"));
+    }
+
+    #[test]
+    fn check_every_length() {
+        let data: Vec<u8> = (0..512u32).map(|i| (i * 31 + 7) as u8).collect();
+        for len in 0..=data.len() {
+            let data = &data[..len];
+            assert_eq!(
+                golden::<Byond>(data),
+                checksum::<Byond>(data),
+                "length {}",
+                len
+            );
+            assert_eq!(golden::<Png>(data), checksum::<Png>(data), "length {}", len);
+            assert_eq!(
+                golden::<Mpeg2>(data),
+                checksum::<Mpeg2>(data),
+                "length {}",
+                len
+            );
+            assert_eq!(
+                golden::<Castagnoli>(data),
+                checksum::<Castagnoli>(data),
+                "length {}",
+                len
+            );
+        }
+    }
+
+    #[quickcheck]
+    fn byond_matches_crc32(chunks: Vec<Vec<u8>>) -> bool {
+        let mut crc = Crc::<Byond>::new();
+        let mut crc32 = crate::Crc32::new();
+        for chunk in chunks {
+            crc.update(&chunk);
+            crc32.update(&chunk);
+        }
+        crc.as_u32() == crc32.as_u32() && crc.len() == crc32.len()
+    }
+
+    #[quickcheck]
+    fn png_chunks_match_golden(chunks: Vec<Vec<u8>>) -> bool {
+        let mut crc = Crc::<Png>::new();
+        for chunk in &chunks {
+            crc.update(chunk);
+        }
+        crc.as_u32() == golden::<Png>(&chunks.concat())
+    }
+}
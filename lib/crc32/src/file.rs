@@ -0,0 +1,143 @@
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::Path,
+};
+
+use memmap2::Mmap;
+
+use crate::Crc32;
+
+/// The size of the buffer for files which can't be memory-mapped.
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// Computes the CRC-32/BYOND checksum of the file at `path`, returning the
+/// checksum along with the length of the file.
+///
+/// Regular files are memory-mapped rather than read, to avoid copying their
+/// data, and checksummed concurrently with the `rayon` feature. Pipes,
+/// devices and other files which can't be mapped are read through a buffer
+/// instead.
+///
+/// # Safety
+///
+/// A mapped file is read as a `&[u8]`, so the file must not be modified or
+/// truncated, by this process or any other, until this returns. Modifying it
+/// is undefined behavior, and on unix, truncating it raises `SIGBUS` when the
+/// missing pages are read, which kills the process.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// // SAFETY: nothing else writes to this file.
+/// let crc32 = unsafe { byond_crc32::checksum_file("tgstation.rsc")? };
+/// println!("{:08x} {}", crc32.as_u32(), crc32.len());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub unsafe fn checksum_file<P: AsRef<Path>>(path: P) -> io::Result<Crc32> {
+    let file = File::open(path)?;
+    let metadata = file.metadata()?;
+    // Files such as those in procfs report a length of 0 despite having
+    // contents, and empty files can't be mapped anyway.
+    if metadata.is_file() && metadata.len() > 0 {
+        // SAFETY: the caller guarantees the file isn't modified while mapped.
+        if let Ok(map) = unsafe { Mmap::map(&file) } {
+            #[cfg(unix)]
+            let _ = map.advise(memmap2::Advice::Sequential);
+            let mut crc32 = Crc32::new();
+            update(&mut crc32, &map);
+            return Ok(crc32);
+        }
+    }
+    checksum_reader(file)
+}
+
+#[cfg(feature = "rayon")]
+fn update(crc32: &mut Crc32, bytes: &[u8]) {
+    crc32.par_update(bytes);
+}
+
+#[cfg(not(feature = "rayon"))]
+fn update(crc32: &mut Crc32, bytes: &[u8]) {
+    crc32.update(bytes);
+}
+
+fn checksum_reader<R: Read>(mut reader: R) -> io::Result<Crc32> {
+    let mut crc32 = Crc32::new();
+    let mut buf = vec![0; BUFFER_SIZE];
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => return Ok(crc32),
+            Ok(n) => crc32.update(&buf[..n]),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use quickcheck_macros::quickcheck;
+
+    use crate::{golden, DEFAULT_CRC32};
+
+    /// A file in the temporary directory, removed on drop.
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str, contents: &[u8]) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "byond-crc32-{}-{}-{:?}",
+                name,
+                std::process::id(),
+                std::thread::current().id()
+            ));
+            fs::write(&path, contents).unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    #[quickcheck]
+    fn checksum_file_matches_golden(data: Vec<u8>) -> bool {
+        let file = TempFile::new("golden", &data);
+        let crc32 = unsafe { super::checksum_file(&file.0) }.unwrap();
+        crc32.as_u32() == golden(DEFAULT_CRC32, &data) && crc32.len() == data.len() as u64
+    }
+
+    #[test]
+    fn checksum_large_file() {
+        let data: Vec<u8> = (0..3 * 1024 * 1024 + 7).map(|i| (i * 31) as u8).collect();
+        let file = TempFile::new("large", &data);
+        let crc32 = unsafe { super::checksum_file(&file.0) }.unwrap();
+        assert_eq!(golden(DEFAULT_CRC32, &data), crc32.as_u32());
+        assert_eq!(data.len() as u64, crc32.len());
+    }
+
+    #[quickcheck]
+    fn checksum_reader_matches_golden(data: Vec<u8>) -> bool {
+        let crc32 = super::checksum_reader(data.as_slice()).unwrap();
+        crc32.as_u32() == golden(DEFAULT_CRC32, &data) && crc32.len() == data.len() as u64
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn checksum_special_file() {
+        // Character devices can't be mapped, so this reads instead.
+        let crc32 = unsafe { super::checksum_file("/dev/null") }.unwrap();
+        assert_eq!(crate::Crc32::new(), crc32);
+    }
+
+    #[test]
+    fn checksum_missing_file() {
+        let path = std::env::temp_dir().join("byond-crc32-missing");
+        assert!(unsafe { super::checksum_file(path) }.is_err());
+    }
+}
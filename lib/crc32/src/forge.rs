@@ -0,0 +1,86 @@
+//! Computes bytes which force a CRC-32/BYOND checksum to a target value.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use byond_crc32::{forge, Crc32};
+//!
+//! let mut crc32 = Crc32::new();
+//! crc32.update(b"hello");
+//! crc32.update(&forge::append(&crc32, 0xdeadbeef));
+//! assert_eq!(0xdeadbeef, crc32.as_u32());
+//! ```
+
+use crate::{combine, Crc32};
+
+/// Gets the 4 bytes which, when appended to the data consumed by `crc32`,
+/// result in the `target` checksum.
+pub fn append(crc32: &Crc32, target: u32) -> [u8; 4] {
+    // Consuming a big-endian word `w` maps `crc` to `(crc ^ w) * x^32 mod p`,
+    // so undo the multiplication and cancel out the current checksum.
+    (combine::unzeros(target, 4) ^ crc32.as_u32()).to_be_bytes()
+}
+
+/// Gets the 4 bytes which, when inserted between the data consumed by
+/// `prefix` and the data consumed by `suffix`, result in the `target`
+/// checksum.
+///
+/// As with [`Crc32::combine`], `suffix` must have been computed starting
+/// from the default initial checksum.
+pub fn insert(prefix: &Crc32, suffix: &Crc32, target: u32) -> [u8; 4] {
+    let head = combine::split_prefix(target, suffix.as_u32(), suffix.len());
+    (combine::unzeros(head, 4) ^ prefix.as_u32()).to_be_bytes()
+}
+
+/// Gets the 4 bytes which, when written over the `old` bytes at `offset` in
+/// the data consumed by `crc32`, result in the `target` checksum.
+///
+/// # Panics
+///
+/// Panics if the overwritten range extends past the data consumed so far.
+pub fn overwrite(crc32: &Crc32, offset: u64, old: [u8; 4], target: u32) -> [u8; 4] {
+    let tail = crc32
+        .len()
+        .checked_sub(offset)
+        .and_then(|len| len.checked_sub(4))
+        .expect("overwrite extends past the consumed data");
+    let delta = combine::unzeros(crc32.as_u32() ^ target, tail + 4);
+    (u32::from_be_bytes(old) ^ delta).to_be_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use crate::{golden, Crc32};
+
+    #[quickcheck]
+    fn append_hits_target(init: u32, data: Vec<u8>, target: u32) -> bool {
+        let mut crc32 = Crc32::new_with_initial(init, 0);
+        crc32.update(&data);
+        let forged = super::append(&crc32, target);
+        golden(init, &[data.as_slice(), &forged].concat()) == target
+    }
+
+    #[quickcheck]
+    fn insert_hits_target(a: Vec<u8>, b: Vec<u8>, target: u32) -> bool {
+        let (mut prefix, mut suffix) = (Crc32::new(), Crc32::new());
+        prefix.update(&a);
+        suffix.update(&b);
+        let forged = super::insert(&prefix, &suffix, target);
+        let data = [a.as_slice(), &forged, &b].concat();
+        golden(crate::DEFAULT_CRC32, &data) == target
+    }
+
+    #[quickcheck]
+    fn overwrite_hits_target(init: u32, mut data: Vec<u8>, offset: usize, target: u32) -> bool {
+        data.extend_from_slice(&[1, 2, 3, 4]);
+        let offset = offset % (data.len() - 3);
+        let mut crc32 = Crc32::new_with_initial(init, 0);
+        crc32.update(&data);
+        let old = data[offset..offset + 4].try_into().unwrap();
+        let forged = super::overwrite(&crc32, offset as u64, old, target);
+        data[offset..offset + 4].copy_from_slice(&forged);
+        golden(init, &data) == target
+    }
+}
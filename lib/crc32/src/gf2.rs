@@ -0,0 +1,260 @@
+//! Arithmetic on polynomials over GF(2) modulo the CRC-32/BYOND polynomial.
+//!
+//! A CRC-32/BYOND checksum is a polynomial of degree less than 32 over
+//! GF(2), with bit `i` as the coefficient of x^i. Consuming a zero byte
+//! multiplies the checksum by x^8 modulo
+//! P(x) = x^32 + x^7 + x^5 + x^3 + x^2 + x + 1, and since CRCs are linear,
+//! the checksums of two messages of equal length XOR to the checksum of
+//! their XOR (without any initial value). [`Poly32`] implements this
+//! arithmetic, which is enough to combine, split and forge checksums by
+//! hand.
+//!
+//! P(x) is irreducible, so every nonzero [`Poly32`] has an inverse.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use byond_crc32::{gf2::Poly32, Crc32};
+//!
+//! let mut crc32 = Crc32::new();
+//! crc32.update(b"123456789");
+//!
+//! // Appending 4 zero bytes multiplies the checksum by x^32.
+//! let shifted = Poly32::new(crc32.as_u32()).shift_bytes(4);
+//! assert_eq!(shifted, Poly32::new(crc32.as_u32()) * Poly32::x_pow(32));
+//!
+//! crc32.update(&[0; 4]);
+//! assert_eq!(shifted.as_u32(), crc32.as_u32());
+//! ```
+
+#[cfg(not(feature = "std"))]
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+#[cfg(feature = "std")]
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::{combine, tables::POLY};
+
+/// The CRC-32/BYOND polynomial P(x), including its x^32 term.
+pub const MODULUS: u64 = 1 << 32 | POLY as u64;
+
+/// A polynomial over GF(2) modulo the CRC-32/BYOND polynomial P(x).
+///
+/// Addition and subtraction are both XOR, and multiplication uses
+/// carry-less multiplication instructions if the current CPU supports them.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Poly32(u32);
+
+impl Poly32 {
+    /// The polynomial 0.
+    pub const ZERO: Self = Self(0);
+    /// The polynomial 1.
+    pub const ONE: Self = Self(1);
+    /// The polynomial x.
+    pub const X: Self = Self(2);
+
+    /// Creates a polynomial with bit `i` of `bits` as the coefficient of x^i.
+    pub const fn new(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    /// Gets the coefficients of this polynomial, with bit `i` as the
+    /// coefficient of x^i.
+    pub const fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Gets x^n modulo P(x).
+    pub fn x_pow(n: u64) -> Self {
+        Self(combine::x8nmodp(n / 8)).mul_x((n % 8) as u32)
+    }
+
+    /// Raises this polynomial to the power `n`, modulo P(x).
+    pub fn pow(self, mut n: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::ONE;
+        while n != 0 {
+            if n & 1 != 0 {
+                result *= base;
+            }
+            n >>= 1;
+            base *= base;
+        }
+        result
+    }
+
+    /// Gets the multiplicative inverse of this polynomial modulo P(x), or
+    /// `None` if it is zero.
+    pub fn inverse(self) -> Option<Self> {
+        // The nonzero polynomials modulo P(x) form a group of order 2^32 - 1.
+        (self != Self::ZERO).then(|| self.pow(u64::from(u32::MAX) - 1))
+    }
+
+    /// Multiplies this polynomial by x^(8 * n), which advances a checksum as
+    /// if `n` zero bytes had been consumed.
+    pub fn shift_bytes(self, n: u64) -> Self {
+        Self(combine::zeros(self.0, n))
+    }
+
+    /// Divides this polynomial by x^(8 * n), which rewinds a checksum as if
+    /// `n` zero bytes had been removed from the end of the consumed data.
+    pub fn unshift_bytes(self, n: u64) -> Self {
+        Self(combine::unzeros(self.0, n))
+    }
+
+    /// Multiplies by x^n for n < 8, a bit at a time.
+    fn mul_x(mut self, n: u32) -> Self {
+        for _ in 0..n {
+            self.0 = if self.0 & 0x80000000 != 0 {
+                (self.0 << 1) ^ POLY
+            } else {
+                self.0 << 1
+            };
+        }
+        self
+    }
+}
+
+impl From<u32> for Poly32 {
+    fn from(bits: u32) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Poly32> for u32 {
+    fn from(poly: Poly32) -> Self {
+        poly.0
+    }
+}
+
+impl Add for Poly32 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl AddAssign for Poly32 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Poly32 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl SubAssign for Poly32 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for Poly32 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(combine::mul(self.0, rhs.0))
+    }
+}
+
+impl MulAssign for Poly32 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for Poly32 {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inverse().expect("attempt to divide by zero")
+    }
+}
+
+impl DivAssign for Poly32 {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use super::Poly32;
+    use crate::{golden, DEFAULT_CRC32};
+
+    /// Multiplies the long way, as polynomials, then reduces modulo P(x).
+    fn mul_golden(a: u32, b: u32) -> u32 {
+        let mut prod = 0u64;
+        for i in 0..32 {
+            if a & 1 << i != 0 {
+                prod ^= u64::from(b) << i;
+            }
+        }
+        for i in (32..64).rev() {
+            if prod & 1 << i != 0 {
+                prod ^= super::MODULUS << (i - 32);
+            }
+        }
+        prod as u32
+    }
+
+    #[quickcheck]
+    fn mul_matches_golden(a: u32, b: u32) -> bool {
+        (Poly32::new(a) * Poly32::new(b)).as_u32() == mul_golden(a, b)
+    }
+
+    #[quickcheck]
+    fn x_pow_matches_pow(n: u16) -> bool {
+        Poly32::x_pow(n.into()) == Poly32::X.pow(n.into())
+    }
+
+    #[test]
+    fn x_pow_cycles() {
+        // The nonzero polynomials modulo P(x) form a group of order 2^32 - 1.
+        assert_eq!(Poly32::ONE, Poly32::x_pow(u64::from(u32::MAX)));
+        assert_eq!(Poly32::X, Poly32::x_pow(u64::from(u32::MAX) + 1));
+    }
+
+    #[quickcheck]
+    fn inverse_is_inverse(a: u32) -> bool {
+        match Poly32::new(a).inverse() {
+            Some(inverse) => a != 0 && Poly32::new(a) * inverse == Poly32::ONE,
+            None => a == 0,
+        }
+    }
+
+    #[quickcheck]
+    fn div_undoes_mul(a: u32, b: u32) -> bool {
+        let b = Poly32::new(b | 1);
+        Poly32::new(a) * b / b == Poly32::new(a)
+    }
+
+    #[quickcheck]
+    fn shift_bytes_matches_golden(crc: u32, n: u8) -> bool {
+        let shifted = Poly32::new(crc).shift_bytes(n.into());
+        shifted.as_u32() == golden(crc, &vec![0; n as usize])
+            && shifted.unshift_bytes(n.into()) == Poly32::new(crc)
+    }
+
+    #[quickcheck]
+    fn checksums_are_linear(pairs: Vec<(u8, u8)>) -> bool {
+        // With the initial value, crc(a ^ b) = crc(a) + crc(b) + crc(0).
+        let (a, b): (Vec<u8>, Vec<u8>) = pairs.into_iter().unzip();
+        let xor: Vec<u8> = a.iter().zip(&b).map(|(a, b)| a ^ b).collect();
+        let crc = |data: &[u8]| Poly32::new(golden(DEFAULT_CRC32, data));
+        crc(&xor) == crc(&a) + crc(&b) + crc(&vec![0; a.len()])
+    }
+}
@@ -0,0 +1,146 @@
+#[cfg(not(feature = "std"))]
+use core::hash::{BuildHasher, Hasher};
+#[cfg(feature = "std")]
+use std::hash::{BuildHasher, Hasher};
+
+use crate::{Crc32, DEFAULT_CRC32};
+
+/// Builds [`Crc32Hasher`]s, for keying a `HashMap` or `HashSet` by
+/// CRC-32/BYOND.
+///
+/// Hashing a key through [`Hash`](core::hash::Hash) may feed more than its
+/// raw bytes to the hasher, e.g. `str` appends a `0xff` terminator, so a
+/// key's hash isn't necessarily its checksum.
+///
+/// CRC-32/BYOND offers no protection against collision attacks, so only use
+/// this for keys from trusted sources.
+///
+/// ## Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use byond_crc32::BuildCrc32Hasher;
+///
+/// let mut icons = HashMap::with_hasher(BuildCrc32Hasher::new());
+/// icons.insert("icons/obj/items.dmi", 1);
+/// assert_eq!(Some(&1), icons.get("icons/obj/items.dmi"));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct BuildCrc32Hasher {
+    seed: u32,
+}
+
+impl BuildCrc32Hasher {
+    /// Creates a builder for hashers with the default initial checksum.
+    pub fn new() -> Self {
+        Self::with_seed(DEFAULT_CRC32)
+    }
+
+    /// Creates a builder for hashers with the given initial checksum.
+    pub fn with_seed(seed: u32) -> Self {
+        Self { seed }
+    }
+
+    /// Gets the initial checksum of the hashers this builds.
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+}
+
+impl Default for BuildCrc32Hasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for BuildCrc32Hasher {
+    type Hasher = Crc32Hasher;
+
+    fn build_hasher(&self) -> Crc32Hasher {
+        Crc32Hasher {
+            crc32: Crc32::new_with_initial(self.seed, 0),
+        }
+    }
+}
+
+/// A CRC-32/BYOND hasher for hash tables, created by [`BuildCrc32Hasher`].
+///
+/// Unlike the [`Hasher`] implementation for [`Crc32`], this repeats the
+/// checksum in the high 32 bits of the hash. Hash tables such as `hashbrown`
+/// take tag bits from the top of the hash, which would otherwise always be
+/// zero.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Crc32Hasher {
+    crc32: Crc32,
+}
+
+impl Crc32Hasher {
+    /// Gets the checksum of the data hashed so far.
+    pub fn as_u32(&self) -> u32 {
+        self.crc32.as_u32()
+    }
+}
+
+impl Hasher for Crc32Hasher {
+    fn finish(&self) -> u64 {
+        let crc = u64::from(self.as_u32());
+        crc << 32 | crc
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.crc32.update(bytes);
+    }
+}
+
+/// A [`hashbrown::HashMap`] keyed by CRC-32/BYOND.
+#[cfg(feature = "hashbrown")]
+pub type Crc32HashMap<K, V> = ::hashbrown::HashMap<K, V, BuildCrc32Hasher>;
+
+/// A [`hashbrown::HashSet`] keyed by CRC-32/BYOND.
+#[cfg(feature = "hashbrown")]
+pub type Crc32HashSet<T> = ::hashbrown::HashSet<T, BuildCrc32Hasher>;
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        hash::{BuildHasher, Hasher},
+    };
+
+    use quickcheck_macros::quickcheck;
+
+    use super::BuildCrc32Hasher;
+    use crate::golden;
+
+    #[quickcheck]
+    fn hasher_uses_seed(seed: u32, data: Vec<u8>) -> bool {
+        let mut hasher = BuildCrc32Hasher::with_seed(seed).build_hasher();
+        hasher.write(&data);
+        let crc = golden(seed, &data);
+        hasher.as_u32() == crc && hasher.finish() == u64::from(crc) * 0x1_0000_0001
+    }
+
+    #[quickcheck]
+    fn hash_map_round_trip(keys: Vec<String>) -> bool {
+        let map: HashMap<_, _, BuildCrc32Hasher> = keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| (key.as_str(), i))
+            .collect();
+        keys.iter().all(|key| keys[map[key.as_str()]] == *key)
+    }
+
+    #[cfg(feature = "hashbrown")]
+    #[quickcheck]
+    fn hashbrown_matches_std(keys: Vec<String>, seed: u32) -> bool {
+        let hasher = BuildCrc32Hasher::with_seed(seed);
+        let mut set = super::Crc32HashSet::with_hasher(hasher);
+        let mut std_set = std::collections::HashSet::with_hasher(hasher);
+        for key in &keys {
+            set.insert(key.as_str());
+            std_set.insert(key.as_str());
+        }
+        set.len() == std_set.len() && std_set.iter().all(|key| set.contains(key))
+    }
+}
@@ -0,0 +1,255 @@
+use std::io::{self, BufRead, Read, Write};
+
+use crate::Crc32;
+
+/// Wraps a reader, updating a CRC-32/BYOND checksum with all data read.
+///
+/// # Panics
+///
+/// Through [`BufRead`], consuming more than the last [`BufRead::fill_buf`]
+/// returned panics, as the consumed bytes can't be checksummed.
+#[derive(Debug)]
+pub struct Crc32Reader<R> {
+    inner: R,
+    crc32: Crc32,
+}
+
+impl<R> Crc32Reader<R> {
+    /// Creates a new reader which checksums data read from `inner`.
+    pub fn new(inner: R) -> Self {
+        Self::new_with_crc32(inner, Crc32::new())
+    }
+
+    /// Creates a new reader which continues the given in-progress
+    /// checksum with data read from `inner`.
+    pub fn new_with_crc32(inner: R, crc32: Crc32) -> Self {
+        Self { inner, crc32 }
+    }
+
+    /// Gets the checksum of the data read so far.
+    pub fn as_u32(&self) -> u32 {
+        self.crc32.as_u32()
+    }
+
+    /// Gets the underlying CRC-32/BYOND computation.
+    pub fn crc32(&self) -> &Crc32 {
+        &self.crc32
+    }
+
+    /// Returns true if no data has been read so far.
+    pub fn is_empty(&self) -> bool {
+        self.crc32.is_empty()
+    }
+
+    /// The length of data read so far.
+    pub fn len(&self) -> u64 {
+        self.crc32.len()
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Data read directly from the underlying reader is not checksummed.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps this reader, returning the underlying reader and checksum.
+    pub fn into_inner(self) -> (R, Crc32) {
+        (self.inner, self.crc32)
+    }
+}
+
+impl<R: Read> Read for Crc32Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let amount = self.inner.read(buf)?;
+        self.crc32.update(&buf[..amount]);
+        Ok(amount)
+    }
+}
+
+impl<R: BufRead> BufRead for Crc32Reader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        // `read_until` and friends consume nothing at EOF, and refilling the
+        // buffer then would block for more input.
+        if amt != 0 {
+            // Only consumed bytes count towards the checksum. They must come
+            // from a buffer returned by `fill_buf`, which the inner reader
+            // hands back again without doing any I/O.
+            let buf = self
+                .inner
+                .fill_buf()
+                .expect("consume called without a buffer from fill_buf");
+            assert!(
+                amt <= buf.len(),
+                "consumed more than the buffer returned by fill_buf"
+            );
+            self.crc32.update(&buf[..amt]);
+        }
+        self.inner.consume(amt);
+    }
+}
+
+/// Wraps a writer, updating a CRC-32/BYOND checksum with all data written.
+#[derive(Debug)]
+pub struct Crc32Writer<W> {
+    inner: W,
+    crc32: Crc32,
+}
+
+impl<W> Crc32Writer<W> {
+    /// Creates a new writer which checksums data written to `inner`.
+    pub fn new(inner: W) -> Self {
+        Self::new_with_crc32(inner, Crc32::new())
+    }
+
+    /// Creates a new writer which continues the given in-progress
+    /// checksum with data written to `inner`.
+    pub fn new_with_crc32(inner: W, crc32: Crc32) -> Self {
+        Self { inner, crc32 }
+    }
+
+    /// Gets the checksum of the data written so far.
+    pub fn as_u32(&self) -> u32 {
+        self.crc32.as_u32()
+    }
+
+    /// Gets the underlying CRC-32/BYOND computation.
+    pub fn crc32(&self) -> &Crc32 {
+        &self.crc32
+    }
+
+    /// Returns true if no data has been written so far.
+    pub fn is_empty(&self) -> bool {
+        self.crc32.is_empty()
+    }
+
+    /// The length of data written so far.
+    pub fn len(&self) -> u64 {
+        self.crc32.len()
+    }
+
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// Data written directly to the underlying writer is not checksummed.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwraps this writer, returning the underlying writer and checksum.
+    pub fn into_inner(self) -> (W, Crc32) {
+        (self.inner, self.crc32)
+    }
+}
+
+impl<W: Write> Write for Crc32Writer<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let amount = self.inner.write(buf)?;
+        self.crc32.update(&buf[..amount]);
+        Ok(amount)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, BufRead, BufReader, Read, Write};
+
+    use quickcheck_macros::quickcheck;
+
+    use super::{Crc32Reader, Crc32Writer};
+    use crate::golden;
+
+    #[quickcheck]
+    fn reader_matches_golden(data: Vec<u8>) -> bool {
+        let mut reader = Crc32Reader::new(data.as_slice());
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).unwrap();
+        reader.as_u32() == golden(crate::DEFAULT_CRC32, &data)
+            && reader.len() == data.len() as u64
+            && out == data
+    }
+
+    #[quickcheck]
+    fn buf_reader_matches_golden(data: Vec<u8>) -> bool {
+        let mut reader = Crc32Reader::new(BufReader::with_capacity(7, data.as_slice()));
+        let mut out = Vec::new();
+        reader.read_until(0, &mut out).unwrap();
+        reader.read_to_end(&mut out).unwrap();
+        reader.as_u32() == golden(crate::DEFAULT_CRC32, &data) && reader.len() == data.len() as u64
+    }
+
+    #[test]
+    fn read_until_at_eof_reads_nothing_extra() {
+        struct CountingReader<'a> {
+            data: &'a [u8],
+            reads: usize,
+        }
+
+        impl Read for CountingReader<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.reads += 1;
+                self.data.read(buf)
+            }
+        }
+
+        fn count_reads(wrap: bool) -> usize {
+            let data = b"12345\n6789";
+            let mut reader = BufReader::with_capacity(4, CountingReader { data, reads: 0 });
+            let mut out = Vec::new();
+            if wrap {
+                let mut wrapped = Crc32Reader::new(&mut reader);
+                while wrapped.read_until(b'\n', &mut out).unwrap() != 0 {}
+                assert_eq!(golden(crate::DEFAULT_CRC32, data), wrapped.as_u32());
+            } else {
+                while reader.read_until(b'\n', &mut out).unwrap() != 0 {}
+            }
+            assert_eq!(&data[..], out);
+            reader.get_ref().reads
+        }
+
+        assert_eq!(count_reads(false), count_reads(true));
+    }
+
+    #[test]
+    #[should_panic(expected = "consumed more than the buffer")]
+    fn consume_past_buffer_panics() {
+        let mut reader = Crc32Reader::new(BufReader::with_capacity(4, &b"123456789"[..]));
+        reader.fill_buf().unwrap();
+        reader.consume(5);
+    }
+
+    #[quickcheck]
+    fn writer_matches_golden(data: Vec<u8>) -> bool {
+        let mut writer = Crc32Writer::new(Vec::new());
+        writer.write_all(&data).unwrap();
+        writer.as_u32() == golden(crate::DEFAULT_CRC32, &data)
+            && writer.len() == data.len() as u64
+            && writer.get_ref() == &data
+    }
+
+    #[test]
+    fn copy_through_both() {
+        let mut reader = Crc32Reader::new(&b"123456789"[..]);
+        let mut writer = Crc32Writer::new(io::sink());
+        io::copy(&mut reader, &mut writer).unwrap();
+        assert_eq!(reader.crc32(), writer.crc32());
+        assert_eq!(0xa5fd3138, writer.as_u32());
+    }
+}
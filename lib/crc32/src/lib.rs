@@ -12,9 +12,37 @@
 
 pub mod baseline;
 mod combine;
+#[cfg(feature = "digest")]
+mod digest;
+mod engine;
+#[cfg(feature = "mmap")]
+mod file;
+pub mod forge;
+pub mod gf2;
+mod hash;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod rolling;
+#[cfg(feature = "serde")]
+mod serde;
 pub mod specialized;
 mod tables;
 
+pub use combine::Combiner;
+pub use engine::{Byond, Crc, CrcParams, Png};
+#[cfg(feature = "mmap")]
+pub use file::checksum_file;
+pub use hash::{BuildCrc32Hasher, Crc32Hasher};
+#[cfg(feature = "hashbrown")]
+pub use hash::{Crc32HashMap, Crc32HashSet};
+#[cfg(feature = "std")]
+pub use io::{Crc32Reader, Crc32Writer};
+#[cfg(feature = "rayon")]
+pub use parallel::par_checksum;
+pub use rolling::RollingCrc32;
+
 #[cfg(not(feature = "std"))]
 use core::hash::Hasher;
 #[cfg(feature = "std")]
@@ -22,12 +50,129 @@ use std::hash::Hasher;
 
 const DEFAULT_CRC32: u32 = 0xffffffff;
 
+/// An implementation which a CRC-32/BYOND computation can run on.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Backend {
+    /// The portable, table-driven implementation in [`baseline`].
+    Baseline,
+    /// The carry-less multiplication implementation in [`specialized`], which
+    /// folds 128 bits at a time with PCLMULQDQ on x86 and PMULL on AArch64.
+    /// Other targets don't support it.
+    Specialized,
+    /// The x86 implementation in [`specialized`] which folds 256 bits at a
+    /// time with VPCLMULQDQ and AVX2, for buffers of at least 256B.
+    Vpclmulqdq256,
+    /// The x86 implementation in [`specialized`] which folds 512 bits at a
+    /// time with VPCLMULQDQ and AVX-512, for buffers of at least 256B.
+    Vpclmulqdq512,
+}
+
+impl Backend {
+    /// Returns true if this backend can run on the current CPU.
+    pub fn is_supported(self) -> bool {
+        State::new(self, DEFAULT_CRC32).is_some()
+    }
+}
+
+/// Gets the backends which can run on the current CPU, from slowest to
+/// fastest.
+pub fn available_backends() -> impl Iterator<Item = Backend> {
+    [
+        Backend::Baseline,
+        Backend::Specialized,
+        Backend::Vpclmulqdq256,
+        Backend::Vpclmulqdq512,
+    ]
+    .into_iter()
+    .filter(|backend| backend.is_supported())
+}
+
+/// Computes the CRC-32/BYOND checksum of `bytes` in a `const` context.
+///
+/// This runs a byte at a time, so prefer [`Crc32`] for checksums computed at
+/// runtime. See also [`crc32!`] for string literals.
+///
+/// ## Example
+///
+/// ```rust
+/// const CHECK: u32 = byond_crc32::const_checksum(b"123456789");
+/// assert_eq!(0xa5fd3138, CHECK);
+/// ```
+pub const fn const_checksum(bytes: &[u8]) -> u32 {
+    baseline::slice_by_1(DEFAULT_CRC32, bytes)
+}
+
+/// Computes the CRC-32/BYOND checksum of a string literal at compile time.
+///
+/// ## Example
+///
+/// ```rust
+/// use byond_crc32::crc32;
+///
+/// const DEFAULT: u32 = crc32!("default");
+///
+/// fn is_default(crc: u32) -> bool {
+///     matches!(crc, DEFAULT)
+/// }
+///
+/// assert!(is_default(byond_crc32::const_checksum(b"default")));
+/// ```
+#[macro_export]
+macro_rules! crc32 {
+    ($s:expr) => {{
+        const CRC32: u32 = $crate::const_checksum(::core::primitive::str::as_bytes($s));
+        CRC32
+    }};
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum State {
     Baseline(baseline::State),
     Specialized(specialized::State),
 }
 
+impl State {
+    /// Creates a state which runs on `backend`, or `None` if the backend is
+    /// not supported on the current CPU.
+    fn new(backend: Backend, crc: u32) -> Option<Self> {
+        let state = match backend {
+            Backend::Baseline => State::Baseline(baseline::State::new(crc)),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Backend::Specialized => State::Specialized(specialized::State::with_kernel(crc, None)?),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Backend::Vpclmulqdq256 => State::Specialized(specialized::State::with_kernel(
+                crc,
+                Some(specialized::Kernel::Avx2),
+            )?),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Backend::Vpclmulqdq512 => State::Specialized(specialized::State::with_kernel(
+                crc,
+                Some(specialized::Kernel::Avx512),
+            )?),
+            #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+            Backend::Specialized => State::Specialized(specialized::State::new(crc)?),
+            #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+            Backend::Vpclmulqdq256 | Backend::Vpclmulqdq512 => return None,
+        };
+        Some(state)
+    }
+
+    fn backend(&self) -> Backend {
+        match self {
+            State::Baseline(_) => Backend::Baseline,
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            State::Specialized(state) => match state.kernel() {
+                None => Backend::Specialized,
+                Some(specialized::Kernel::Avx2) => Backend::Vpclmulqdq256,
+                Some(specialized::Kernel::Avx512) => Backend::Vpclmulqdq512,
+            },
+            #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+            State::Specialized(_) => Backend::Specialized,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 /// Represents an in-progress CRC-32/BYOND computation.
 pub struct Crc32 {
@@ -54,6 +199,18 @@ impl Crc32 {
         Self { len, state }
     }
 
+    /// Creates a new CRC-32/BYOND computation hasher which runs on the given
+    /// backend, or `None` if the backend is not supported on the current CPU.
+    pub fn with_backend(backend: Backend) -> Option<Self> {
+        let state = State::new(backend, DEFAULT_CRC32)?;
+        Some(Self { len: 0, state })
+    }
+
+    /// Gets the backend this computation runs on.
+    pub fn backend(&self) -> Backend {
+        self.state.backend()
+    }
+
     /// Gets the underlying checksum value.
     pub fn as_u32(&self) -> u32 {
         match self.state {
@@ -62,6 +219,11 @@ impl Crc32 {
         }
     }
 
+    /// Replaces the underlying checksum value, keeping the current backend.
+    fn set_u32(&mut self, crc: u32) {
+        self.state = State::new(self.backend(), crc).expect("backend is supported");
+    }
+
     /// Returns true if no data has been consumed so far.
     pub fn is_empty(&self) -> bool {
         self.len == 0
@@ -81,6 +243,45 @@ impl Crc32 {
         }
     }
 
+    /// Saves the CRC-32/BYOND computation as a checkpoint, which
+    /// [`Crc32::from_checkpoint`] can resume from later.
+    ///
+    /// The checkpoint holds the checksum followed by the length, both
+    /// big-endian. This format is stable across versions and machines.
+    pub fn to_checkpoint(&self) -> [u8; 12] {
+        let mut checkpoint = [0; 12];
+        checkpoint[..4].copy_from_slice(&self.as_u32().to_be_bytes());
+        checkpoint[4..].copy_from_slice(&self.len.to_be_bytes());
+        checkpoint
+    }
+
+    /// Resumes a CRC-32/BYOND computation from a checkpoint created by
+    /// [`Crc32::to_checkpoint`].
+    ///
+    /// The backend is detected again, so a checkpoint may be resumed on a
+    /// different machine from the one which created it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use byond_crc32::Crc32;
+    ///
+    /// let mut crc32 = Crc32::new();
+    /// crc32.update(b"12345");
+    /// let checkpoint = crc32.to_checkpoint();
+    ///
+    /// let mut crc32 = Crc32::from_checkpoint(checkpoint);
+    /// crc32.update(b"6789");
+    /// assert_eq!(0xa5fd3138, crc32.as_u32());
+    /// ```
+    pub fn from_checkpoint(checkpoint: [u8; 12]) -> Self {
+        let (crc, len) = checkpoint.split_at(4);
+        Self::new_with_initial(
+            u32::from_be_bytes(crc.try_into().unwrap()),
+            u64::from_be_bytes(len.try_into().unwrap()),
+        )
+    }
+
     /// Updates the CRC-32/BYOND computation with the given `bytes`.
     pub fn update(&mut self, bytes: &[u8]) {
         self.len += bytes.len() as u64;
@@ -90,11 +291,151 @@ impl Crc32 {
         }
     }
 
+    /// Reverses [`Crc32::update`], removing `bytes` from the end of the data
+    /// consumed so far.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is longer than the data consumed so far.
+    pub fn unupdate(&mut self, bytes: &[u8]) {
+        self.len = self
+            .len
+            .checked_sub(bytes.len() as u64)
+            .expect("unupdate removes more data than was consumed");
+        self.set_u32(baseline::unslice_by_1(self.as_u32(), bytes));
+    }
+
+    /// Updates the CRC-32/BYOND computation as if `n` zero bytes had been
+    /// consumed, in time logarithmic in `n`.
+    pub fn update_zeros(&mut self, n: u64) {
+        self.len += n;
+        self.set_u32(combine::zeros(self.as_u32(), n));
+    }
+
+    /// Updates the CRC-32/BYOND computation as if the consumed bytes `old`
+    /// at `offset` had instead been `new`.
+    ///
+    /// This takes time linear in the length of the patch and logarithmic in
+    /// the length of the data following it, without rehashing any other data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `old` and `new` differ in length, or if the patched range
+    /// extends past the data consumed so far.
+    pub fn patch(&mut self, offset: u64, old: &[u8], new: &[u8]) {
+        assert_eq!(old.len(), new.len(), "patch changes the data length");
+        let end = offset
+            .checked_add(old.len() as u64)
+            .filter(|&end| end <= self.len)
+            .expect("patch extends past the consumed data");
+
+        // CRCs are linear, so the checksum of the difference between the old
+        // and new data (without any initial value) can be XORed in directly.
+        let mut delta = 0;
+        let mut buf = [0u8; 64];
+        for (old, new) in old.chunks(buf.len()).zip(new.chunks(buf.len())) {
+            for ((b, &old), &new) in buf.iter_mut().zip(old).zip(new) {
+                *b = old ^ new;
+            }
+            delta = baseline::slice_by_16(delta, &buf[..old.len()]);
+        }
+        let crc = self.as_u32() ^ combine::zeros(delta, self.len - end);
+        self.set_u32(crc);
+    }
+
     /// Combines two CRC-32/BYOND checksums.
     pub fn combine(a: &Self, b: &Self) -> Self {
         let (crc1, crc2) = (a.as_u32(), b.as_u32());
         Self::new_with_initial(combine::combine(crc1, crc2, b.len), a.len + b.len)
     }
+
+    /// Combines a sequence of CRC-32/BYOND checksums, each given with the
+    /// length of data it is over, into the checksum of all of their data.
+    ///
+    /// Checksums are combined pairwise in a balanced tree. Subtrees at the
+    /// same depth reuse a [`Combiner`] while their lengths match, so
+    /// combining many equal-sized blocks only computes a few operators.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use byond_crc32::Crc32;
+    ///
+    /// let blocks: Vec<_> = b"123456789"
+    ///     .chunks(2)
+    ///     .map(|block| {
+    ///         let mut crc32 = Crc32::new();
+    ///         crc32.update(block);
+    ///         (crc32.as_u32(), crc32.len())
+    ///     })
+    ///     .collect();
+    /// assert_eq!(0xa5fd3138, Crc32::combine_many(blocks));
+    /// ```
+    pub fn combine_many<I: IntoIterator<Item = (u32, u64)>>(iter: I) -> Self {
+        // A binary counter of subtrees, each holding its checksum, length and
+        // height. There are fewer than 2^64 items, so it never overflows.
+        let mut stack = [(DEFAULT_CRC32, 0u64, 0u32); 65];
+        let mut depth = 0;
+        let mut combiners: [Option<Combiner>; 64] = [None; 64];
+        let mut merge = |(crc1, len1, height): (u32, u64, u32), (crc2, len2, _)| {
+            let combiner = match combiners[height as usize] {
+                Some(combiner) if combiner.len() == len2 => combiner,
+                _ => {
+                    let combiner = Combiner::for_len(len2);
+                    combiners[height as usize] = Some(combiner);
+                    combiner
+                }
+            };
+            (combiner.combine_u32(crc1, crc2), len1 + len2, height + 1)
+        };
+
+        for (crc, len) in iter {
+            stack[depth] = (crc, len, 0);
+            depth += 1;
+            while depth >= 2 && stack[depth - 1].2 == stack[depth - 2].2 {
+                stack[depth - 2] = merge(stack[depth - 2], stack[depth - 1]);
+                depth -= 1;
+            }
+        }
+        while depth >= 2 {
+            stack[depth - 2] = merge(stack[depth - 2], stack[depth - 1]);
+            depth -= 1;
+        }
+        let (crc, len, _) = stack[0];
+        Self::new_with_initial(crc, len)
+    }
+
+    /// Splits the CRC-32/BYOND checksum of `A || B` given the checksum of
+    /// `A`, returning the checksum of `B`.
+    ///
+    /// This is the inverse of [`Crc32::combine`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `prefix` is longer than `whole`.
+    pub fn split_suffix(whole: &Self, prefix: &Self) -> Self {
+        assert!(prefix.len <= whole.len, "prefix is longer than whole");
+        let len2 = whole.len - prefix.len;
+        let (crc, crc1) = (whole.as_u32(), prefix.as_u32());
+        Self::new_with_initial(combine::split_suffix(crc, crc1, len2), len2)
+    }
+
+    /// Splits the CRC-32/BYOND checksum of `A || B` given the checksum of
+    /// `B`, returning the checksum of `A`.
+    ///
+    /// This is the inverse of [`Crc32::combine`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `suffix` is longer than `whole`.
+    pub fn split_prefix(whole: &Self, suffix: &Self) -> Self {
+        assert!(suffix.len <= whole.len, "suffix is longer than whole");
+        let (crc, crc2) = (whole.as_u32(), suffix.as_u32());
+        Self::new_with_initial(
+            combine::split_prefix(crc, crc2, suffix.len),
+            whole.len - suffix.len,
+        )
+    }
 }
 
 impl Default for Crc32 {
@@ -160,6 +501,70 @@ mod tests {
         crc32.as_u32() == golden(crate::DEFAULT_CRC32, data.as_slice())
     }
 
+    #[test]
+    fn const_checksum_is_valid() {
+        const CRC32: u32 = crate::crc32!("123456789");
+        assert_eq!(CHECK, CRC32);
+        assert_eq!(CHECK, super::const_checksum(b"123456789"));
+    }
+
+    #[quickcheck]
+    fn check_const_checksum(data: Vec<u8>) -> bool {
+        super::const_checksum(&data) == golden(crate::DEFAULT_CRC32, &data)
+    }
+
+    #[quickcheck]
+    fn check_backends(data: Vec<u8>) -> bool {
+        super::available_backends().all(|backend| {
+            let mut crc32 = super::Crc32::with_backend(backend).unwrap();
+            crc32.update(data.as_slice());
+            crc32.backend() == backend
+                && crc32.as_u32() == golden(crate::DEFAULT_CRC32, data.as_slice())
+        })
+    }
+
+    #[quickcheck]
+    fn check_backends_long(data: Vec<u8>, len: u16) -> bool {
+        // Long enough for the wide kernels to run.
+        let data: Vec<u8> = (0..256 + len as usize)
+            .map(|i| {
+                data.get(i % data.len().max(1))
+                    .map_or(i as u8, |&b| b ^ i as u8)
+            })
+            .collect();
+        super::available_backends().all(|backend| {
+            let mut crc32 = super::Crc32::with_backend(backend).unwrap();
+            crc32.update(&data);
+            crc32.update_zeros(1);
+            crc32.backend() == backend
+                && crc32.as_u32() == golden(crate::DEFAULT_CRC32, &[&data[..], &[0]].concat())
+        })
+    }
+
+    #[test]
+    fn new_picks_fastest_backend() {
+        assert_eq!(
+            super::available_backends().last(),
+            Some(super::Crc32::new().backend())
+        );
+    }
+
+    #[test]
+    fn with_backend_requires_support() {
+        for backend in [
+            super::Backend::Baseline,
+            super::Backend::Specialized,
+            super::Backend::Vpclmulqdq256,
+            super::Backend::Vpclmulqdq512,
+        ] {
+            assert_eq!(
+                backend.is_supported(),
+                super::Crc32::with_backend(backend).is_some()
+            );
+        }
+        assert!(super::available_backends().any(|b| b == super::Backend::Baseline));
+    }
+
     #[test]
     fn check_combine() {
         let mut crc_a = super::Crc32::new();
@@ -168,4 +573,123 @@ mod tests {
         crc_b.update(b"6789");
         assert_eq!(CHECK, super::Crc32::combine(&crc_a, &crc_b));
     }
+
+    #[quickcheck]
+    fn check_combiner(a: Vec<u8>, blocks: Vec<u8>, size: u8) -> bool {
+        let size = size as usize + 1;
+        let combiner = super::Combiner::for_len(size as u64);
+        let mut crc32 = super::Crc32::new();
+        crc32.update(&a);
+        for block in blocks.chunks_exact(size) {
+            let mut block_crc32 = super::Crc32::new();
+            block_crc32.update(block);
+            crc32 = combiner.combine(&crc32, &block_crc32);
+        }
+        let data = [&a, &blocks[..blocks.len() - blocks.len() % size]].concat();
+        crc32.as_u32() == golden(crate::DEFAULT_CRC32, &data) && crc32.len() == data.len() as u64
+    }
+
+    #[quickcheck]
+    fn check_combine_many(chunks: Vec<Vec<u8>>) -> bool {
+        let crc32 = super::Crc32::combine_many(
+            chunks
+                .iter()
+                .map(|chunk| (golden(crate::DEFAULT_CRC32, chunk), chunk.len() as u64)),
+        );
+        let data = chunks.concat();
+        crc32.as_u32() == golden(crate::DEFAULT_CRC32, &data) && crc32.len() == data.len() as u64
+    }
+
+    #[quickcheck]
+    fn check_combine_many_blocks(data: Vec<u8>, size: u8) -> bool {
+        let crc32 = super::Crc32::combine_many(
+            data.chunks(size as usize + 1)
+                .map(|block| (golden(crate::DEFAULT_CRC32, block), block.len() as u64)),
+        );
+        crc32.as_u32() == golden(crate::DEFAULT_CRC32, &data) && crc32.len() == data.len() as u64
+    }
+
+    #[quickcheck]
+    fn check_split(a: Vec<u8>, b: Vec<u8>) -> bool {
+        let whole = [a.as_slice(), b.as_slice()].concat();
+        let whole = super::Crc32::new_with_initial(
+            golden(crate::DEFAULT_CRC32, &whole),
+            whole.len() as u64,
+        );
+        let prefix =
+            super::Crc32::new_with_initial(golden(crate::DEFAULT_CRC32, &a), a.len() as u64);
+        let suffix =
+            super::Crc32::new_with_initial(golden(crate::DEFAULT_CRC32, &b), b.len() as u64);
+        super::Crc32::split_suffix(&whole, &prefix) == suffix
+            && super::Crc32::split_prefix(&whole, &suffix) == prefix
+    }
+
+    #[quickcheck]
+    fn check_patch(init: u32, mut data: Vec<u8>, offset: usize, new: Vec<u8>) -> bool {
+        let offset = offset % (data.len() + 1);
+        let new = &new[..new.len().min(data.len() - offset)];
+        let old = data[offset..offset + new.len()].to_vec();
+        let mut crc32 = super::Crc32::new_with_initial(init, 0);
+        crc32.update(&data);
+        crc32.patch(offset as u64, &old, new);
+        data[offset..offset + new.len()].copy_from_slice(new);
+        crc32.as_u32() == golden(init, &data) && crc32.len() == data.len() as u64
+    }
+
+    #[quickcheck]
+    fn check_unupdate(init: u32, a: Vec<u8>, b: Vec<u8>) -> bool {
+        let mut crc32 = super::Crc32::new_with_initial(init, 0);
+        crc32.update(&a);
+        let expected = crc32;
+        crc32.update(&b);
+        crc32.unupdate(&b);
+        crc32 == expected
+    }
+
+    #[test]
+    fn checkpoint_is_stable() {
+        let mut crc32 = super::Crc32::new();
+        crc32.update(b"123456789");
+        assert_eq!(
+            [0xa5, 0xfd, 0x31, 0x38, 0, 0, 0, 0, 0, 0, 0, 9],
+            crc32.to_checkpoint()
+        );
+    }
+
+    #[quickcheck]
+    fn check_checkpoint(init: u32, a: Vec<u8>, b: Vec<u8>) -> bool {
+        let mut crc32 = super::Crc32::new_with_initial(init, 0);
+        crc32.update(&a);
+        let mut resumed = super::Crc32::from_checkpoint(crc32.to_checkpoint());
+        resumed.update(&b);
+        crc32.update(&b);
+        resumed == crc32
+    }
+
+    #[quickcheck]
+    fn checkpoint_redetects_backend(data: Vec<u8>) -> bool {
+        let mut baseline = super::Crc32::with_backend(super::Backend::Baseline).unwrap();
+        baseline.update(&data);
+        let resumed = super::Crc32::from_checkpoint(baseline.to_checkpoint());
+        resumed == super::Crc32::new_with_initial(baseline.as_u32(), data.len() as u64)
+            && resumed.as_u32() == golden(crate::DEFAULT_CRC32, &data)
+    }
+
+    #[quickcheck]
+    fn check_update_zeros(init: u32, n: u16) -> bool {
+        let mut crc32 = super::Crc32::new_with_initial(init, 0);
+        crc32.update_zeros(n as u64);
+        crc32.as_u32() == golden(init, &vec![0; n as usize]) && crc32.len() == n as u64
+    }
+
+    #[quickcheck]
+    fn check_update_zeros_splits(init: u32, a: u64, b: u64) -> bool {
+        let (a, b) = (a >> 2, b >> 2);
+        let mut whole = super::Crc32::new_with_initial(init, 0);
+        whole.update_zeros(a + b);
+        let mut split = super::Crc32::new_with_initial(init, 0);
+        split.update_zeros(a);
+        split.update_zeros(b);
+        whole == split
+    }
 }
@@ -0,0 +1,85 @@
+use rayon::prelude::*;
+
+use crate::{combine, Crc32, DEFAULT_CRC32};
+
+/// The amount of data each thread checksums before results are combined.
+const CHUNK_SIZE: usize = 1024 * 1024;
+
+impl Crc32 {
+    /// Updates the CRC-32/BYOND computation with the given `bytes`,
+    /// checksumming chunks of the input concurrently.
+    ///
+    /// The result is identical to [`Crc32::update`]; small inputs are
+    /// checksummed on the current thread. Every chunk is checksummed on this
+    /// computation's backend.
+    pub fn par_update(&mut self, bytes: &[u8]) {
+        self.par_update_chunked(bytes, CHUNK_SIZE);
+    }
+
+    fn par_update_chunked(&mut self, bytes: &[u8], chunk_size: usize) {
+        if bytes.len() <= chunk_size {
+            self.update(bytes);
+            return;
+        }
+        let mut empty = *self;
+        empty.reset();
+        let (crc, len) = bytes
+            .par_chunks(chunk_size)
+            .map(|chunk| {
+                let mut crc32 = empty;
+                crc32.update(chunk);
+                (crc32.as_u32(), crc32.len())
+            })
+            .reduce(
+                || (DEFAULT_CRC32, 0),
+                |(crc1, len1), (crc2, len2)| (combine::combine(crc1, crc2, len2), len1 + len2),
+            );
+        self.len += len;
+        self.set_u32(combine::combine(self.as_u32(), crc, len));
+    }
+}
+
+/// Computes the CRC-32/BYOND checksum of `bytes`, checksumming chunks of
+/// the input concurrently.
+pub fn par_checksum(bytes: &[u8]) -> u32 {
+    let mut crc32 = Crc32::new();
+    crc32.par_update(bytes);
+    crc32.as_u32()
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use crate::{golden, Crc32};
+
+    #[quickcheck]
+    fn par_update_matches_golden(init: u32, bytes: Vec<u8>, chunk_size: u8) -> bool {
+        let mut crc32 = Crc32::new_with_initial(init, 0);
+        crc32.par_update_chunked(&bytes, chunk_size as usize + 1);
+        crc32.as_u32() == golden(init, &bytes) && crc32.len() == bytes.len() as u64
+    }
+
+    #[test]
+    fn par_update_keeps_backend() {
+        let bytes = vec![13; 4096];
+        for backend in crate::available_backends() {
+            let mut crc32 = Crc32::with_backend(backend).unwrap();
+            crc32.par_update_chunked(&bytes, 100);
+            assert_eq!(backend, crc32.backend());
+            assert_eq!(crc32, golden(crate::DEFAULT_CRC32, &bytes));
+        }
+    }
+
+    #[test]
+    fn par_checksum_matches_update() {
+        let bytes: Vec<u8> = (0..super::CHUNK_SIZE * 3 + 17)
+            .map(|i| (i * 31 % 251) as u8)
+            .collect();
+        for len in [0, 1, super::CHUNK_SIZE, super::CHUNK_SIZE + 1, bytes.len()] {
+            let mut crc32 = Crc32::new();
+            crc32.update(&bytes[..len]);
+            assert_eq!(crc32, super::par_checksum(&bytes[..len]));
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{baseline, combine, tables::BYTE_TABLE, DEFAULT_CRC32};
+
+/// Represents a CRC-32/BYOND computation over a sliding window of bytes,
+/// such as for content-defined chunking.
+///
+/// The window initially holds `window` zero bytes.
+///
+/// ## Example
+///
+/// ```rust
+/// use byond_crc32::{Crc32, RollingCrc32};
+///
+/// let data = b"0123456789";
+/// let mut rolling = RollingCrc32::new(4);
+/// for (i, &byte) in data.iter().enumerate() {
+///     let out = if i >= 4 { data[i - 4] } else { 0 };
+///     rolling.roll(out, byte);
+/// }
+///
+/// let mut crc32 = Crc32::new();
+/// crc32.update(b"6789");
+/// assert_eq!(crc32.as_u32(), rolling.as_u32());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RollingCrc32 {
+    window: usize,
+    state: u32,
+    out_table: [u32; 256],
+}
+
+impl RollingCrc32 {
+    /// Creates a new rolling CRC-32/BYOND computation over a window of
+    /// `window` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    pub fn new(window: usize) -> Self {
+        assert!(window > 0, "window must not be empty");
+        // A byte leaving the window contributes its `BYTE_TABLE` entry shifted
+        // past the remainder of the window. The initial checksum's
+        // contribution also moves forward a byte on every roll, so fold the
+        // difference into every entry.
+        let shift = combine::x8nmodp(window as u64);
+        let init = combine::multmodp(shift, DEFAULT_CRC32);
+        let init = init ^ combine::zeros(init, 1);
+        let mut out_table = [0; 256];
+        for (out, &entry) in out_table.iter_mut().zip(BYTE_TABLE.iter()) {
+            *out = combine::multmodp(shift, entry) ^ init;
+        }
+        let mut rolling = Self {
+            window,
+            state: 0,
+            out_table,
+        };
+        rolling.reset();
+        rolling
+    }
+
+    /// Gets the checksum of the current window.
+    pub fn as_u32(&self) -> u32 {
+        self.state
+    }
+
+    /// The length of the window in bytes.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Resets the window to hold only zero bytes.
+    pub fn reset(&mut self) {
+        self.state = combine::zeros(DEFAULT_CRC32, self.window as u64);
+    }
+
+    /// Slides the window forward a byte, removing `out_byte` from the front
+    /// of the window and adding `in_byte` to the back.
+    #[inline]
+    pub fn roll(&mut self, out_byte: u8, in_byte: u8) {
+        self.state =
+            baseline::slice_by_1(self.state, &[in_byte]) ^ self.out_table[out_byte as usize];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use crate::golden;
+
+    #[quickcheck]
+    fn roll_matches_golden(data: Vec<u8>, window: u8) -> bool {
+        let window = window as usize % 64 + 1;
+        let mut padded = vec![0; window];
+        padded.extend_from_slice(&data);
+        let mut rolling = super::RollingCrc32::new(window);
+        padded.windows(window + 1).all(|bytes| {
+            rolling.roll(bytes[0], bytes[window]);
+            rolling.as_u32() == golden(crate::DEFAULT_CRC32, &bytes[1..])
+        })
+    }
+
+    #[test]
+    fn reset_matches_zero_window() {
+        let rolling = super::RollingCrc32::new(16);
+        assert_eq!(rolling.as_u32(), golden(crate::DEFAULT_CRC32, &[0; 16]));
+    }
+}
@@ -0,0 +1,56 @@
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Crc32;
+
+/// The serialized form of [`Crc32`], which leaves out the backend so it can
+/// be detected again on deserialization.
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "Crc32")]
+struct Checkpoint {
+    crc: u32,
+    len: u64,
+}
+
+impl Serialize for Crc32 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Checkpoint {
+            crc: self.as_u32(),
+            len: self.len(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Crc32 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Checkpoint { crc, len } = Checkpoint::deserialize(deserializer)?;
+        Ok(Crc32::new_with_initial(crc, len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_tokens, Token};
+
+    use crate::Crc32;
+
+    #[test]
+    fn tokens() {
+        let mut crc32 = Crc32::new();
+        crc32.update(b"123456789");
+        assert_tokens(
+            &crc32,
+            &[
+                Token::Struct {
+                    name: "Crc32",
+                    len: 2,
+                },
+                Token::Str("crc"),
+                Token::U32(0xa5fd3138),
+                Token::Str("len"),
+                Token::U64(9),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
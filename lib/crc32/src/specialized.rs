@@ -1,32 +1,71 @@
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+mod keys;
+
 cfg_if::cfg_if! {
-    if #[cfg(all(
-        any(target_arch = "x86", target_arch = "x86_64")
-    ))] {
+    if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
         mod pclmulqdq;
+        mod vpclmulqdq;
         pub use pclmulqdq::State;
+        pub(crate) use pclmulqdq::{multmodp, Engine};
+        pub(crate) use vpclmulqdq::Kernel;
+    } else if #[cfg(target_arch = "aarch64")] {
+        mod pmull;
+        pub use pmull::State;
+        pub(crate) use pmull::{multmodp, Engine};
     } else {
-        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
-        pub enum State {}
-        impl State {
-            pub fn new(_: u32) -> Option<Self> {
-                None
-            }
-
-            pub fn update(&mut self, _buf: &[u8]) {
-                unimplemented!()
-            }
-
-            pub fn finalize(self) -> u32 {
-                unimplemented!()
-            }
-
-            pub fn reset(&mut self) {
-                unimplemented!()
-            }
-
-            pub fn combine(&mut self, _other: u32, _amount: u64) {
-                unimplemented!()
-            }
+        pub use fallback::State;
+        pub(crate) use fallback::{multmodp, Engine};
+    }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+mod fallback {
+    use crate::engine::CrcParams;
+
+    /// This target has no specialized backend, so `Crc32` always runs on
+    /// [`baseline`](crate::baseline).
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub struct State {
+        state: u32,
+    }
+
+    impl State {
+        pub fn new(_state: u32) -> Option<Self> {
+            None
+        }
+
+        pub fn update(&mut self, buf: &[u8]) {
+            self.state = crate::baseline::slice_by_16(self.state, buf);
+        }
+
+        pub fn as_u32(&self) -> u32 {
+            self.state
         }
+
+        pub fn reset(&mut self) {
+            self.state = crate::DEFAULT_CRC32;
+        }
+    }
+
+    /// This target has no backend for arbitrary CRC parameters, so the engine
+    /// always falls back to slice-by-16.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Engine {}
+
+    #[allow(clippy::extra_unused_type_parameters)]
+    impl Engine {
+        pub fn detect<P: CrcParams>() -> Option<Self> {
+            None
+        }
+
+        pub fn checksum<P: CrcParams>(self, _crc: u32, _buf: &[u8]) -> u32 {
+            match self {}
+        }
+    }
+
+    /// This target has no carry-less multiplication, so [`crate::gf2`]
+    /// always falls back to shift-and-add.
+    pub fn multmodp(_a: u32, _b: u32) -> Option<u32> {
+        None
     }
 }
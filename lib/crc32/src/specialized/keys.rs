@@ -0,0 +1,84 @@
+// Folding and Barrett reduction constants for the 128-bit carry-less
+// multiplication backends.
+//
+// Folding a 128-bit block across `n` bits multiplies its high and low halves
+// by x^(n+32) and x^(n-32) modulo P(x), each shifted left by 32 bits.
+
+use crate::{engine::CrcParams, tables::xnmodp};
+
+/// The keys for a non-reflected set of CRC parameters.
+pub(crate) trait Keys: CrcParams {
+    const RK01: u64 = key(Self::POLY, 128 - 32);
+    const RK02: u64 = key(Self::POLY, 128 + 32);
+    const RK03: u64 = key(Self::POLY, 1024 - 32);
+    const RK04: u64 = key(Self::POLY, 1024 + 32);
+    const RK05: u64 = key(Self::POLY, 128 - 32);
+    const RK06: u64 = key(Self::POLY, 64);
+    const RK07: u64 = barrett_mu(Self::POLY);
+    const RK08: u64 = 1 << 32 | Self::POLY as u64;
+    const RK09: u64 = key(Self::POLY, 896 - 32);
+    const RK10: u64 = key(Self::POLY, 896 + 32);
+    const RK11: u64 = key(Self::POLY, 768 - 32);
+    const RK12: u64 = key(Self::POLY, 768 + 32);
+    const RK13: u64 = key(Self::POLY, 640 - 32);
+    const RK14: u64 = key(Self::POLY, 640 + 32);
+    const RK15: u64 = key(Self::POLY, 512 - 32);
+    const RK16: u64 = key(Self::POLY, 512 + 32);
+    const RK17: u64 = key(Self::POLY, 384 - 32);
+    const RK18: u64 = key(Self::POLY, 384 + 32);
+    const RK19: u64 = key(Self::POLY, 256 - 32);
+    const RK20: u64 = key(Self::POLY, 256 + 32);
+}
+
+impl<P: CrcParams> Keys for P {}
+
+/// x^bits modulo P(x), shifted left by 32 bits.
+pub(crate) const fn key(poly: u32, bits: u64) -> u64 {
+    (xnmodp(poly, bits) as u64) << 32
+}
+
+/// x^64 divided by P(x), for Barrett reduction.
+pub(crate) const fn barrett_mu(poly: u32) -> u64 {
+    let p = 1 << 32 | poly as u64;
+    let mut rem = 1u128 << 64;
+    let mut quotient = 0;
+    let mut bit = 32;
+    loop {
+        if rem & 1 << (bit + 32) != 0 {
+            rem ^= (p as u128) << bit;
+            quotient |= 1 << bit;
+        }
+        if bit == 0 {
+            break quotient;
+        }
+        bit -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Keys;
+    use crate::Byond;
+
+    #[test]
+    fn keys_match_literals() {
+        #[rustfmt::skip]
+        let literals = [
+            0x0029_5f23_0000_0000, 0xfafa_5179_0000_0000, 0x5cd8_6bb5_0000_0000,
+            0xaf6f_37a3_0000_0000, 0x0029_5f23_0000_0000, 0x0000_4455_0000_0000,
+            0x0000_0001_0000_00af, 0x0000_0001_0000_00af, 0x9bd5_7b5d_0000_0000,
+            0xb7a4_d764_0000_0000, 0x1ae0_0042_0000_0000, 0xe772_0be6_0000_0000,
+            0x9c7f_c8fe_0000_0000, 0x3885_faf8_0000_0000, 0xb477_ad71_0000_0000,
+            0x0ac2_ae3d_0000_0000, 0x5eae_9dbe_0000_0000, 0x784a_4838_0000_0000,
+            0x7d21_bf20_0000_0000, 0xfaeb_d3d3_0000_0000,
+        ];
+        #[rustfmt::skip]
+        let keys = [
+            Byond::RK01, Byond::RK02, Byond::RK03, Byond::RK04, Byond::RK05,
+            Byond::RK06, Byond::RK07, Byond::RK08, Byond::RK09, Byond::RK10,
+            Byond::RK11, Byond::RK12, Byond::RK13, Byond::RK14, Byond::RK15,
+            Byond::RK16, Byond::RK17, Byond::RK18, Byond::RK19, Byond::RK20,
+        ];
+        assert_eq!(literals, keys);
+    }
+}
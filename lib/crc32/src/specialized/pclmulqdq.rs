@@ -3,35 +3,45 @@ use core::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::*;
 
+use super::{
+    keys::{barrett_mu, Keys},
+    vpclmulqdq::{self, Kernel},
+};
+use crate::{
+    engine::{Byond, CrcParams},
+    tables::xnmodp,
+};
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct State {
     state: u32,
+    kernel: Option<Kernel>,
 }
 
 impl State {
-    #[cfg(not(feature = "std"))]
+    /// Creates a state which runs on the widest kernel the current CPU
+    /// supports.
     pub fn new(state: u32) -> Option<Self> {
-        if cfg!(all(
-            target_feature = "sse4.1"
-            target_feature = "pclmulqdq",
-        )) {
-            Some(Self { state })
-        } else {
-            None
-        }
+        Self::with_kernel(state, vpclmulqdq::kernel())
     }
 
-    #[cfg(feature = "std")]
-    pub fn new(state: u32) -> Option<Self> {
-        if is_x86_feature_detected!("pclmulqdq") && is_x86_feature_detected!("sse4.1") {
-            Some(Self { state })
+    /// Creates a state which runs on the given wide kernel, or only on the
+    /// PCLMULQDQ kernel for `None`, if the current CPU supports it.
+    pub(crate) fn with_kernel(state: u32, kernel: Option<Kernel>) -> Option<Self> {
+        if is_supported() && kernel.is_none_or(vpclmulqdq::is_supported) {
+            Some(Self { state, kernel })
         } else {
             None
         }
     }
 
+    /// Gets the wide kernel this state runs on, if any.
+    pub(crate) fn kernel(&self) -> Option<Kernel> {
+        self.kernel
+    }
+
     pub fn update(&mut self, buf: &[u8]) {
-        self.state = unsafe { calculate(self.state, buf) }
+        self.state = unsafe { dispatch::<Byond>(self.kernel, self.state, buf) }
     }
 
     pub fn as_u32(&self) -> u32 {
@@ -43,32 +53,136 @@ impl State {
     }
 }
 
-const RK01: u64 = 0x0029_5f23_0000_0000;
-const RK02: u64 = 0xfafa_5179_0000_0000;
-const RK03: u64 = 0x5cd8_6bb5_0000_0000;
-const RK04: u64 = 0xaf6f_37a3_0000_0000;
-const RK05: u64 = 0x0029_5f23_0000_0000;
-const RK06: u64 = 0x0000_4455_0000_0000;
-const RK07: u64 = 0x0000_0001_0000_00af;
-const RK08: u64 = 0x0000_0001_0000_00af;
-const RK09: u64 = 0x9bd5_7b5d_0000_0000;
-const RK10: u64 = 0xb7a4_d764_0000_0000;
-const RK11: u64 = 0x1ae0_0042_0000_0000;
-const RK12: u64 = 0xe772_0be6_0000_0000;
-const RK13: u64 = 0x9c7f_c8fe_0000_0000;
-const RK14: u64 = 0x3885_faf8_0000_0000;
-const RK15: u64 = 0xb477_ad71_0000_0000;
-const RK16: u64 = 0x0ac2_ae3d_0000_0000;
-const RK17: u64 = 0x5eae_9dbe_0000_0000;
-const RK18: u64 = 0x784a_4838_0000_0000;
-const RK19: u64 = 0x7d21_bf20_0000_0000;
-const RK20: u64 = 0xfaeb_d3d3_0000_0000;
+/// This backend for arbitrary CRC parameters, detected once on the current
+/// CPU.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Engine {
+    kernel: Option<Kernel>,
+}
+
+impl Engine {
+    /// Detects this backend for the CRC parameters `P`, or `None` if the
+    /// current CPU doesn't support it.
+    #[allow(clippy::extra_unused_type_parameters)]
+    pub fn detect<P: CrcParams>() -> Option<Self> {
+        if is_supported() {
+            Some(Self {
+                kernel: vpclmulqdq::kernel(),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Computes a checksum with the CRC parameters `P`, which this engine
+    /// was detected for.
+    pub fn checksum<P: CrcParams>(self, crc: u32, buf: &[u8]) -> u32 {
+        unsafe { dispatch::<P>(self.kernel, crc, buf) }
+    }
+}
+
+/// Computes a(x) * b(x) modulo P(x), or `None` if the current CPU doesn't
+/// support this backend.
+pub fn multmodp(a: u32, b: u32) -> Option<u32> {
+    if is_supported() {
+        Some(unsafe { clmul_multmodp(a, b) })
+    } else {
+        None
+    }
+}
 
 #[target_feature(enable = "pclmulqdq", enable = "sse4.1")]
-pub unsafe fn calculate(crc: u32, mut data: &[u8]) -> u32 {
+unsafe fn clmul_multmodp(a: u32, b: u32) -> u32 {
+    let x = _mm_clmulepi64_si128(
+        _mm_cvtsi32_si128(a as i32),
+        _mm_cvtsi32_si128(b as i32),
+        0x00,
+    );
+
+    // Barrett reduction, 64b to 32b.
+    let t1 = _mm_clmulepi64_si128(
+        _mm_srli_epi64(x, 32),
+        _mm_set_epi64x(0, Byond::RK07 as i64),
+        0x00,
+    );
+    let t2 = _mm_clmulepi64_si128(
+        _mm_srli_epi64(t1, 32),
+        _mm_set_epi64x(0, Byond::RK08 as i64),
+        0x00,
+    );
+    _mm_cvtsi128_si32(_mm_xor_si128(x, t2)) as u32
+}
+
+/// Computes a checksum with the CRC parameters `P` on the given wide kernel,
+/// given that the current CPU supports it and at least PCLMULQDQ and SSE4.1.
+///
+/// Buffers shorter than 256B, and reflected CRCs, always run on PCLMULQDQ.
+#[target_feature(enable = "pclmulqdq", enable = "sse4.1")]
+unsafe fn dispatch<P: CrcParams>(kernel: Option<Kernel>, crc: u32, buf: &[u8]) -> u32 {
+    if P::REFLECTED {
+        return calculate_reflected::<P>(crc, buf);
+    }
+    if buf.len() < 256 {
+        return calculate::<P>(crc, buf);
+    }
+    match kernel {
+        Some(Kernel::Avx512) => vpclmulqdq::calculate_avx512::<P>(crc, buf),
+        Some(Kernel::Avx2) => vpclmulqdq::calculate_avx2::<P>(crc, buf),
+        None => calculate::<P>(crc, buf),
+    }
+}
+
+#[cfg(feature = "std")]
+fn is_supported() -> bool {
+    is_x86_feature_detected!("pclmulqdq") && is_x86_feature_detected!("sse4.1")
+}
+
+#[cfg(not(feature = "std"))]
+fn is_supported() -> bool {
+    use core::sync::atomic::{AtomicU8, Ordering};
+
+    const UNKNOWN: u8 = 0;
+    const SUPPORTED: u8 = 1;
+    const UNSUPPORTED: u8 = 2;
+    static SUPPORT: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+    if cfg!(all(target_feature = "sse4.1", target_feature = "pclmulqdq")) {
+        return true;
+    }
+    match SUPPORT.load(Ordering::Relaxed) {
+        UNKNOWN => {
+            let supported = detect();
+            let support = if supported { SUPPORTED } else { UNSUPPORTED };
+            SUPPORT.store(support, Ordering::Relaxed);
+            supported
+        }
+        support => support == SUPPORTED,
+    }
+}
+
+/// Queries CPUID directly, for builds without `std` feature detection.
+///
+/// Every x86 target Rust supports is at least an i586, which has CPUID.
+#[cfg(any(test, not(feature = "std")))]
+fn detect() -> bool {
+    // Leaf 1 reports PCLMULQDQ in ECX bit 1 and SSE4.1 in ECX bit 19.
+    let ecx = __cpuid(1).ecx;
+    ecx & (1 << 1) != 0 && ecx & (1 << 19) != 0
+}
+
+/// Byte shuffle indices for shifting a register by 0 to 16 bytes. Loading 16
+/// bytes at `16 - n` shifts right by `16 - n` bytes, keeping the top `n` bytes,
+/// and loading 16 bytes at `32 - n` shifts left by `n` bytes.
+static SHIFT_TABLE: [u8; 48] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+];
+
+#[target_feature(enable = "pclmulqdq", enable = "sse4.1")]
+pub unsafe fn calculate<P: Keys>(crc: u32, mut data: &[u8]) -> u32 {
     if data.len() < 16 * 8 * 2 {
-        // This could be handled in intrinsics, but this seems fine for now.
-        return crate::baseline::slice_by_16(crc, data);
+        return calculate_short::<P>(crc, data);
     }
 
     let crc = _mm_set_epi32(crc as i32, 0x0000, 0x0000, 0x0000);
@@ -88,7 +202,7 @@ pub unsafe fn calculate(crc: u32, mut data: &[u8]) -> u32 {
     let mut x0 = get(&mut data, smask);
     x7 = _mm_xor_si128(x7, crc);
 
-    let k3k4 = _mm_set_epi64x(RK04 as i64, RK03 as i64);
+    let k3k4 = _mm_set_epi64x(P::RK04 as i64, P::RK03 as i64);
     while data.len() >= 128 {
         x7 = reduce128(x7, get(&mut data, smask), k3k4);
         x6 = reduce128(x6, get(&mut data, smask), k3k4);
@@ -100,21 +214,49 @@ pub unsafe fn calculate(crc: u32, mut data: &[u8]) -> u32 {
         x0 = reduce128(x0, get(&mut data, smask), k3k4);
     }
 
-    let k1k2 = _mm_set_epi64x(RK02 as i64, RK01 as i64);
-    let mut x = reduce128(x7, x0, _mm_set_epi64x(RK10 as i64, RK09 as i64));
-    x = reduce128(x6, x, _mm_set_epi64x(RK12 as i64, RK11 as i64));
-    x = reduce128(x5, x, _mm_set_epi64x(RK14 as i64, RK13 as i64));
-    x = reduce128(x4, x, _mm_set_epi64x(RK16 as i64, RK15 as i64));
-    x = reduce128(x3, x, _mm_set_epi64x(RK18 as i64, RK17 as i64));
-    x = reduce128(x2, x, _mm_set_epi64x(RK20 as i64, RK19 as i64));
+    let k1k2 = _mm_set_epi64x(P::RK02 as i64, P::RK01 as i64);
+    let mut x = reduce128(x7, x0, _mm_set_epi64x(P::RK10 as i64, P::RK09 as i64));
+    x = reduce128(x6, x, _mm_set_epi64x(P::RK12 as i64, P::RK11 as i64));
+    x = reduce128(x5, x, _mm_set_epi64x(P::RK14 as i64, P::RK13 as i64));
+    x = reduce128(x4, x, _mm_set_epi64x(P::RK16 as i64, P::RK15 as i64));
+    x = reduce128(x3, x, _mm_set_epi64x(P::RK18 as i64, P::RK17 as i64));
+    x = reduce128(x2, x, _mm_set_epi64x(P::RK20 as i64, P::RK19 as i64));
     x = reduce128(x1, x, k1k2);
 
+    finish::<P>(x, data)
+}
+
+/// Folds the remaining data into `x`, then reduces it down to the final
+/// checksum.
+#[target_feature(enable = "pclmulqdq", enable = "sse4.1")]
+pub(super) unsafe fn finish<P: Keys>(mut x: __m128i, mut data: &[u8]) -> u32 {
+    let smask = _mm_set_epi8(
+        0x0, 0x1, 0x2, 0x3, 0x4, 0x5, 0x6, 0x7, 0x8, 0x9, 0xa, 0xb, 0xc, 0xd, 0xe, 0xf,
+    );
+    let k1k2 = _mm_set_epi64x(P::RK02 as i64, P::RK01 as i64);
     while data.len() >= 16 {
         x = reduce128(x, get(&mut data, smask), k1k2);
     }
 
+    if !data.is_empty() {
+        // Shift the remaining bytes into the bottom of `x`, and fold the bytes
+        // shifted out of the top of `x` over it.
+        let n = data.len();
+        let tail = partial(data);
+        let shr = _mm_loadu_si128(SHIFT_TABLE[16 - n..].as_ptr() as *const __m128i);
+        let shl = _mm_loadu_si128(SHIFT_TABLE[32 - n..].as_ptr() as *const __m128i);
+        x = reduce128(
+            _mm_shuffle_epi8(x, shr),
+            _mm_xor_si128(
+                _mm_shuffle_epi8(x, shl),
+                _mm_set_epi64x((tail >> 64) as i64, tail as i64),
+            ),
+            k1k2,
+        );
+    }
+
     // Reduce 128b to 64b.
-    let k5k6 = _mm_set_epi64x(RK06 as i64, RK05 as i64);
+    let k5k6 = _mm_set_epi64x(P::RK06 as i64, P::RK05 as i64);
     x = _mm_xor_si128(_mm_clmulepi64_si128(x, k5k6, 0x01), _mm_slli_si128(x, 8));
     x = _mm_xor_si128(
         _mm_clmulepi64_si128(_mm_srli_si128(x, 12), k5k6, 0x10),
@@ -122,22 +264,113 @@ pub unsafe fn calculate(crc: u32, mut data: &[u8]) -> u32 {
     );
 
     // Barrett reduction, 64b to 32b.
-    let k7k8 = _mm_set_epi64x(RK08 as i64, RK07 as i64);
+    let k7k8 = _mm_set_epi64x(P::RK08 as i64, P::RK07 as i64);
     let t1 = _mm_slli_si128(_mm_clmulepi64_si128(x, k7k8, 0x01), 4);
     let t2 = _mm_slli_si128(_mm_clmulepi64_si128(t1, k7k8, 0x11), 4);
-    let crc = _mm_extract_epi32(_mm_xor_si128(x, t2), 1) as u32;
+    _mm_extract_epi32(_mm_xor_si128(x, t2), 1) as u32
+}
 
-    if data.is_empty() {
-        crc
-    } else {
-        // We could use intrinsics for the remaining data, but this seems fine for now.
-        // Less than 16B remaining, so slice-by-1 instead of slice-by-16.
-        crate::baseline::slice_by_1(crc, data)
+/// Computes the checksum of fewer than 256B, which is too short for the
+/// 128B folding loop in [`calculate`].
+#[target_feature(enable = "pclmulqdq", enable = "sse4.1")]
+unsafe fn calculate_short<P: Keys>(crc: u32, mut data: &[u8]) -> u32 {
+    let smask = _mm_set_epi8(
+        0x0, 0x1, 0x2, 0x3, 0x4, 0x5, 0x6, 0x7, 0x8, 0x9, 0xa, 0xb, 0xc, 0xd, 0xe, 0xf,
+    );
+    if data.len() >= 16 {
+        let x = _mm_xor_si128(
+            get(&mut data, smask),
+            _mm_set_epi32(crc as i32, 0x0000, 0x0000, 0x0000),
+        );
+        return finish::<P>(x, data);
+    }
+    if data.len() < 4 {
+        // The initial CRC would overhang the start of the data, and a few
+        // table lookups are cheaper than a full reduction anyway.
+        return crate::baseline::slice_by_1_with::<P>(crc, data);
+    }
+
+    // Leading zeros don't change a checksum with no initial value, so pad
+    // the data out to a single block, with the initial CRC XORed into its
+    // first four bytes.
+    let x = partial(data) ^ u128::from(crc) << (8 * data.len() - 32);
+    finish::<P>(_mm_set_epi64x((x >> 64) as i64, x as i64), &[])
+}
+
+/// The keys for a reflected set of CRC parameters. These are the same as for
+/// [`Keys`], but bit-reflected and shifted left by 1 bit rather than 32.
+trait ReflectedKeys: CrcParams {
+    const K1: u64 = reflected_key(Self::POLY, 512 + 32);
+    const K2: u64 = reflected_key(Self::POLY, 512 - 32);
+    const K3: u64 = reflected_key(Self::POLY, 128 + 32);
+    const K4: u64 = reflected_key(Self::POLY, 128 - 32);
+    const K5: u64 = reflected_key(Self::POLY, 64);
+    const P_X: u64 = (Self::POLY.reverse_bits() as u64) << 1 | 1;
+    const U_PRIME: u64 = barrett_mu(Self::POLY).reverse_bits() >> 31;
+}
+
+impl<P: CrcParams> ReflectedKeys for P {}
+
+const fn reflected_key(poly: u32, bits: u64) -> u64 {
+    (xnmodp(poly, bits).reverse_bits() as u64) << 1
+}
+
+/// Computes the checksum for reflected CRC parameters, folding 64B per
+/// iteration. Reflected data needs no byte-swapping.
+#[target_feature(enable = "pclmulqdq", enable = "sse4.1")]
+unsafe fn calculate_reflected<P: ReflectedKeys>(crc: u32, mut data: &[u8]) -> u32 {
+    if data.len() < 128 {
+        return crate::baseline::slice_by_16_with::<P>(crc, data);
+    }
+
+    // Load initial 64B of data and XOR in the initial CRC.
+    let mut x3 = get_reflected(&mut data);
+    let mut x2 = get_reflected(&mut data);
+    let mut x1 = get_reflected(&mut data);
+    let mut x0 = get_reflected(&mut data);
+    x3 = _mm_xor_si128(x3, _mm_cvtsi32_si128(crc as i32));
+
+    let k1k2 = _mm_set_epi64x(P::K2 as i64, P::K1 as i64);
+    while data.len() >= 64 {
+        x3 = reduce128(x3, get_reflected(&mut data), k1k2);
+        x2 = reduce128(x2, get_reflected(&mut data), k1k2);
+        x1 = reduce128(x1, get_reflected(&mut data), k1k2);
+        x0 = reduce128(x0, get_reflected(&mut data), k1k2);
+    }
+
+    let k3k4 = _mm_set_epi64x(P::K4 as i64, P::K3 as i64);
+    let mut x = reduce128(x3, x2, k3k4);
+    x = reduce128(x, x1, k3k4);
+    x = reduce128(x, x0, k3k4);
+
+    while data.len() >= 16 {
+        x = reduce128(x, get_reflected(&mut data), k3k4);
     }
+
+    // Reduce 128b to 64b.
+    x = _mm_xor_si128(_mm_clmulepi64_si128(x, k3k4, 0x10), _mm_srli_si128(x, 8));
+    let low32 = _mm_set_epi32(0, 0, 0, !0);
+    x = _mm_xor_si128(
+        _mm_clmulepi64_si128(
+            _mm_and_si128(x, low32),
+            _mm_set_epi64x(0, P::K5 as i64),
+            0x00,
+        ),
+        _mm_srli_si128(x, 4),
+    );
+
+    // Barrett reduction, 64b to 32b.
+    let pu = _mm_set_epi64x(P::U_PRIME as i64, P::P_X as i64);
+    let t1 = _mm_clmulepi64_si128(_mm_and_si128(x, low32), pu, 0x10);
+    let t2 = _mm_clmulepi64_si128(_mm_and_si128(t1, low32), pu, 0x00);
+    let crc = _mm_extract_epi32(_mm_xor_si128(x, t2), 1) as u32;
+
+    // Less than 16B remaining, so slice-by-1 instead of slice-by-16.
+    crate::baseline::slice_by_1_with::<P>(crc, data)
 }
 
 #[inline(always)]
-unsafe fn reduce128(a: __m128i, b: __m128i, keys: __m128i) -> __m128i {
+pub(super) unsafe fn reduce128(a: __m128i, b: __m128i, keys: __m128i) -> __m128i {
     let t1 = _mm_clmulepi64_si128(a, keys, 0x00);
     let t2 = _mm_clmulepi64_si128(a, keys, 0x11);
     _mm_xor_si128(_mm_xor_si128(b, t1), t2)
@@ -150,10 +383,57 @@ unsafe fn get(data: &mut &[u8], smask: __m128i) -> __m128i {
     r
 }
 
+#[inline(always)]
+unsafe fn get_reflected(data: &mut &[u8]) -> __m128i {
+    let r = _mm_loadu_si128(data.as_ptr() as *const __m128i);
+    *data = &data[16..];
+    r
+}
+
+/// Reads fewer than 16 bytes as a big-endian integer, in the same byte order
+/// as [`get`]. Overlapping loads avoid reading past the end of `data`.
+#[inline(always)]
+fn partial(data: &[u8]) -> u128 {
+    let n = data.len();
+    if n >= 8 {
+        let first = u64::from_be_bytes(data[..8].try_into().unwrap());
+        let last = u64::from_be_bytes(data[n - 8..].try_into().unwrap());
+        u128::from(first) << (8 * (n - 8)) | u128::from(last)
+    } else if n >= 4 {
+        let first = u32::from_be_bytes(data[..4].try_into().unwrap());
+        let last = u32::from_be_bytes(data[n - 4..].try_into().unwrap());
+        u128::from(first) << (8 * (n - 4)) | u128::from(last)
+    } else {
+        data.iter().fold(0, |x, &b| x << 8 | u128::from(b))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use quickcheck_macros::quickcheck;
 
+    #[test]
+    fn detect_matches_std() {
+        let expected = is_x86_feature_detected!("pclmulqdq") && is_x86_feature_detected!("sse4.1");
+        assert_eq!(expected, super::detect());
+        assert_eq!(expected, super::is_supported());
+    }
+
+    #[test]
+    fn check_every_length() {
+        if !super::is_supported() {
+            return;
+        }
+        let data: Vec<u8> = (0..512u32).map(|i| (i * 31 + 7) as u8).collect();
+        for len in 0..=data.len() {
+            for init in [0, crate::DEFAULT_CRC32, 0x1234_5678] {
+                let expected = crate::baseline::slice_by_16(init, &data[..len]);
+                let actual = unsafe { super::calculate::<crate::Byond>(init, &data[..len]) };
+                assert_eq!(expected, actual, "length {}", len);
+            }
+        }
+    }
+
     #[quickcheck]
     fn check_against_baseline(init: u32, chunks: Vec<(Vec<u8>, usize)>) -> bool {
         let mut baseline = crate::baseline::State::new(init);
@@ -0,0 +1,206 @@
+use core::arch::aarch64::*;
+
+use super::keys::Keys;
+use crate::engine::{Byond, CrcParams};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct State {
+    state: u32,
+}
+
+impl State {
+    pub fn new(state: u32) -> Option<Self> {
+        if is_supported() {
+            Some(Self { state })
+        } else {
+            None
+        }
+    }
+
+    pub fn update(&mut self, buf: &[u8]) {
+        self.state = unsafe { calculate::<Byond>(self.state, buf) }
+    }
+
+    pub fn as_u32(&self) -> u32 {
+        self.state
+    }
+
+    pub fn reset(&mut self) {
+        self.state = crate::DEFAULT_CRC32;
+    }
+}
+
+/// This backend for arbitrary CRC parameters, detected once on the current
+/// CPU.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Engine(());
+
+impl Engine {
+    /// Detects this backend for the CRC parameters `P`, or `None` if the
+    /// current CPU doesn't support it. Reflected CRCs aren't supported.
+    pub fn detect<P: CrcParams>() -> Option<Self> {
+        if !P::REFLECTED && is_supported() {
+            Some(Self(()))
+        } else {
+            None
+        }
+    }
+
+    /// Computes a checksum with the CRC parameters `P`, which this engine
+    /// was detected for.
+    pub fn checksum<P: CrcParams>(self, crc: u32, buf: &[u8]) -> u32 {
+        unsafe { calculate::<P>(crc, buf) }
+    }
+}
+
+/// Computes a(x) * b(x) modulo P(x), or `None` if the current CPU doesn't
+/// support this backend.
+pub fn multmodp(a: u32, b: u32) -> Option<u32> {
+    if is_supported() {
+        Some(unsafe { pmull_multmodp(a, b) })
+    } else {
+        None
+    }
+}
+
+#[target_feature(enable = "neon", enable = "aes")]
+unsafe fn pmull_multmodp(a: u32, b: u32) -> u32 {
+    let x = vmull_p64(u64::from(a), u64::from(b));
+
+    // Barrett reduction, 64b to 32b.
+    let t1 = vmull_p64((x >> 32) as u64, Byond::RK07);
+    let t2 = vmull_p64((t1 >> 32) as u64, Byond::RK08);
+    (x ^ t2) as u32
+}
+
+#[cfg(feature = "std")]
+fn is_supported() -> bool {
+    std::arch::is_aarch64_feature_detected!("pmull")
+}
+
+#[cfg(not(feature = "std"))]
+fn is_supported() -> bool {
+    cfg!(target_feature = "aes")
+}
+
+#[target_feature(enable = "neon", enable = "aes")]
+pub unsafe fn calculate<P: Keys>(crc: u32, mut data: &[u8]) -> u32 {
+    if data.len() < 16 * 8 * 2 {
+        // Unlike `pclmulqdq::calculate_short`, buffers too short for the
+        // folding loop don't fold here yet.
+        return crate::baseline::slice_by_16_with::<P>(crc, data);
+    }
+
+    let crc = vcombine_u64(vcreate_u64(0), vcreate_u64(u64::from(crc) << 32));
+
+    // Load initial 128B of data and XOR in the initial CRC.
+    let mut x7 = get(&mut data);
+    let mut x6 = get(&mut data);
+    let mut x5 = get(&mut data);
+    let mut x4 = get(&mut data);
+    let mut x3 = get(&mut data);
+    let mut x2 = get(&mut data);
+    let mut x1 = get(&mut data);
+    let mut x0 = get(&mut data);
+    x7 = veorq_u64(x7, crc);
+
+    let k3k4 = keys(P::RK03, P::RK04);
+    while data.len() >= 128 {
+        x7 = reduce128(x7, get(&mut data), k3k4);
+        x6 = reduce128(x6, get(&mut data), k3k4);
+        x5 = reduce128(x5, get(&mut data), k3k4);
+        x4 = reduce128(x4, get(&mut data), k3k4);
+        x3 = reduce128(x3, get(&mut data), k3k4);
+        x2 = reduce128(x2, get(&mut data), k3k4);
+        x1 = reduce128(x1, get(&mut data), k3k4);
+        x0 = reduce128(x0, get(&mut data), k3k4);
+    }
+
+    let k1k2 = keys(P::RK01, P::RK02);
+    let mut x = reduce128(x7, x0, keys(P::RK09, P::RK10));
+    x = reduce128(x6, x, keys(P::RK11, P::RK12));
+    x = reduce128(x5, x, keys(P::RK13, P::RK14));
+    x = reduce128(x4, x, keys(P::RK15, P::RK16));
+    x = reduce128(x3, x, keys(P::RK17, P::RK18));
+    x = reduce128(x2, x, keys(P::RK19, P::RK20));
+    x = reduce128(x1, x, k1k2);
+
+    while data.len() >= 16 {
+        x = reduce128(x, get(&mut data), k1k2);
+    }
+
+    // The remaining reductions run once, so work on the folded value as a
+    // scalar rather than juggling vector lanes.
+    let mut x = u128::from(vgetq_lane_u64(x, 0)) | u128::from(vgetq_lane_u64(x, 1)) << 64;
+
+    // Reduce 128b to 64b.
+    x = vmull_p64((x >> 64) as u64, P::RK05) ^ (x << 64);
+    x = vmull_p64((x >> 96) as u64, P::RK06) ^ (x & ((1 << 96) - 1));
+
+    // Barrett reduction, 64b to 32b.
+    let t1 = vmull_p64((x >> 64) as u64, P::RK07) << 32;
+    let t2 = vmull_p64((t1 >> 64) as u64, P::RK08) << 32;
+    let crc = ((x ^ t2) >> 32) as u32;
+
+    if data.is_empty() {
+        crc
+    } else {
+        // Less than 16B remaining, so slice-by-1 instead of slice-by-16.
+        crate::baseline::slice_by_1_with::<P>(crc, data)
+    }
+}
+
+#[inline]
+#[target_feature(enable = "neon", enable = "aes")]
+unsafe fn keys(lo: u64, hi: u64) -> uint64x2_t {
+    vcombine_u64(vcreate_u64(lo), vcreate_u64(hi))
+}
+
+#[inline]
+#[target_feature(enable = "neon", enable = "aes")]
+unsafe fn reduce128(a: uint64x2_t, b: uint64x2_t, keys: uint64x2_t) -> uint64x2_t {
+    let t1 = vmull_p64(vgetq_lane_u64(a, 0), vgetq_lane_u64(keys, 0));
+    let t2 = vmull_high_p64(vreinterpretq_p64_u64(a), vreinterpretq_p64_u64(keys));
+    veorq_u64(
+        veorq_u64(b, vreinterpretq_u64_p128(t1)),
+        vreinterpretq_u64_p128(t2),
+    )
+}
+
+#[inline]
+#[target_feature(enable = "neon", enable = "aes")]
+unsafe fn get(data: &mut &[u8]) -> uint64x2_t {
+    // Byte-swap all 16 bytes, so the first byte is the most significant.
+    let r = vrev64q_u8(vld1q_u8(data.as_ptr()));
+    let r = vreinterpretq_u64_u8(vextq_u8(r, r, 8));
+    *data = &data[16..];
+    r
+}
+
+#[cfg(test)]
+mod test {
+    use quickcheck_macros::quickcheck;
+
+    #[quickcheck]
+    fn check_against_baseline(init: u32, chunks: Vec<(Vec<u8>, usize)>) -> bool {
+        let mut baseline = crate::baseline::State::new(init);
+        let mut pmull = match super::State::new(init) {
+            Some(pmull) => pmull,
+            // Without `std`, PMULL support is only known at compile time.
+            None if cfg!(not(feature = "std")) => return true,
+            None => panic!("not supported"),
+        };
+        for (chunk, mut offset) in chunks {
+            // simulate random alignments by offsetting the slice by up to 15 bytes
+            offset &= 0xF;
+            if chunk.len() <= offset {
+                baseline.update(&chunk);
+                pmull.update(&chunk);
+            } else {
+                baseline.update(&chunk[offset..]);
+                pmull.update(&chunk[offset..]);
+            }
+        }
+        pmull.as_u32() == baseline.as_u32()
+    }
+}
@@ -0,0 +1,272 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use super::{
+    keys::{key, Keys},
+    pclmulqdq::{finish, reduce128},
+};
+
+/// Keys for folding across 2048 bits (256B).
+pub(super) trait WideKeys: Keys {
+    const RK2048_LO: u64 = key(Self::POLY, 2048 - 32);
+    const RK2048_HI: u64 = key(Self::POLY, 2048 + 32);
+}
+
+impl<P: Keys> WideKeys for P {}
+
+/// A wide folding kernel, which folds several 128-bit lanes per instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kernel {
+    /// Four 128-bit lanes per register, with AVX-512 and VPCLMULQDQ.
+    Avx512,
+    /// Two 128-bit lanes per register, with AVX2 and VPCLMULQDQ.
+    Avx2,
+}
+
+/// Gets the widest kernel the current CPU supports, if any.
+pub fn kernel() -> Option<Kernel> {
+    [Kernel::Avx512, Kernel::Avx2]
+        .into_iter()
+        .find(|&kernel| is_supported(kernel))
+}
+
+/// Returns true if the current CPU supports `kernel`.
+#[cfg(feature = "std")]
+pub fn is_supported(kernel: Kernel) -> bool {
+    is_x86_feature_detected!("vpclmulqdq")
+        && match kernel {
+            Kernel::Avx512 => {
+                is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512bw")
+            }
+            Kernel::Avx2 => is_x86_feature_detected!("avx2"),
+        }
+}
+
+/// Returns true if the current CPU supports `kernel`.
+///
+/// Without `std`, only the features enabled at compile time are used, since
+/// AVX state support also depends on the OS.
+#[cfg(not(feature = "std"))]
+pub fn is_supported(kernel: Kernel) -> bool {
+    match kernel {
+        Kernel::Avx512 => cfg!(all(
+            target_feature = "vpclmulqdq",
+            target_feature = "avx512f",
+            target_feature = "avx512bw"
+        )),
+        Kernel::Avx2 => cfg!(all(target_feature = "vpclmulqdq", target_feature = "avx2")),
+    }
+}
+
+/// Computes the checksum by folding 256B per iteration across four 512-bit
+/// registers. `data` must be at least 256B long.
+#[target_feature(
+    enable = "avx512f",
+    enable = "avx512bw",
+    enable = "vpclmulqdq",
+    enable = "pclmulqdq",
+    enable = "sse4.1"
+)]
+pub unsafe fn calculate_avx512<P: WideKeys>(crc: u32, mut data: &[u8]) -> u32 {
+    debug_assert!(data.len() >= 256);
+
+    let crc = _mm512_zextsi128_si512(_mm_set_epi32(crc as i32, 0x0000, 0x0000, 0x0000));
+    // Shuffle mask for byte-swapping each 16 bytes.
+    let smask = _mm512_broadcast_i32x4(_mm_set_epi8(
+        0x0, 0x1, 0x2, 0x3, 0x4, 0x5, 0x6, 0x7, 0x8, 0x9, 0xa, 0xb, 0xc, 0xd, 0xe, 0xf,
+    ));
+
+    // Load initial 256B of data and XOR in the initial CRC.
+    let mut x3 = get512(&mut data, smask);
+    let mut x2 = get512(&mut data, smask);
+    let mut x1 = get512(&mut data, smask);
+    let mut x0 = get512(&mut data, smask);
+    x3 = _mm512_xor_si512(x3, crc);
+
+    let k2048 = _mm512_broadcast_i32x4(_mm_set_epi64x(P::RK2048_HI as i64, P::RK2048_LO as i64));
+    while data.len() >= 256 {
+        x3 = reduce512(x3, get512(&mut data, smask), k2048);
+        x2 = reduce512(x2, get512(&mut data, smask), k2048);
+        x1 = reduce512(x1, get512(&mut data, smask), k2048);
+        x0 = reduce512(x0, get512(&mut data, smask), k2048);
+    }
+
+    let k512 = _mm512_broadcast_i32x4(_mm_set_epi64x(P::RK16 as i64, P::RK15 as i64));
+    let mut x = reduce512(x3, x2, k512);
+    x = reduce512(x, x1, k512);
+    x = reduce512(x, x0, k512);
+
+    while data.len() >= 64 {
+        x = reduce512(x, get512(&mut data, smask), k512);
+    }
+
+    // Reduce the four 128-bit lanes into one.
+    let mut y = reduce128(
+        _mm512_extracti32x4_epi32(x, 0),
+        _mm512_extracti32x4_epi32(x, 3),
+        _mm_set_epi64x(P::RK18 as i64, P::RK17 as i64),
+    );
+    y = reduce128(
+        _mm512_extracti32x4_epi32(x, 1),
+        y,
+        _mm_set_epi64x(P::RK20 as i64, P::RK19 as i64),
+    );
+    y = reduce128(
+        _mm512_extracti32x4_epi32(x, 2),
+        y,
+        _mm_set_epi64x(P::RK02 as i64, P::RK01 as i64),
+    );
+
+    finish::<P>(y, data)
+}
+
+/// Computes the checksum by folding 256B per iteration across eight 256-bit
+/// registers. `data` must be at least 256B long.
+#[target_feature(
+    enable = "avx2",
+    enable = "vpclmulqdq",
+    enable = "pclmulqdq",
+    enable = "sse4.1"
+)]
+pub unsafe fn calculate_avx2<P: WideKeys>(crc: u32, mut data: &[u8]) -> u32 {
+    debug_assert!(data.len() >= 256);
+
+    let crc = _mm256_zextsi128_si256(_mm_set_epi32(crc as i32, 0x0000, 0x0000, 0x0000));
+    // Shuffle mask for byte-swapping each 16 bytes.
+    let smask = _mm256_broadcastsi128_si256(_mm_set_epi8(
+        0x0, 0x1, 0x2, 0x3, 0x4, 0x5, 0x6, 0x7, 0x8, 0x9, 0xa, 0xb, 0xc, 0xd, 0xe, 0xf,
+    ));
+
+    // Load initial 256B of data and XOR in the initial CRC.
+    let mut x7 = get256(&mut data, smask);
+    let mut x6 = get256(&mut data, smask);
+    let mut x5 = get256(&mut data, smask);
+    let mut x4 = get256(&mut data, smask);
+    let mut x3 = get256(&mut data, smask);
+    let mut x2 = get256(&mut data, smask);
+    let mut x1 = get256(&mut data, smask);
+    let mut x0 = get256(&mut data, smask);
+    x7 = _mm256_xor_si256(x7, crc);
+
+    let k2048 =
+        _mm256_broadcastsi128_si256(_mm_set_epi64x(P::RK2048_HI as i64, P::RK2048_LO as i64));
+    while data.len() >= 256 {
+        x7 = reduce256(x7, get256(&mut data, smask), k2048);
+        x6 = reduce256(x6, get256(&mut data, smask), k2048);
+        x5 = reduce256(x5, get256(&mut data, smask), k2048);
+        x4 = reduce256(x4, get256(&mut data, smask), k2048);
+        x3 = reduce256(x3, get256(&mut data, smask), k2048);
+        x2 = reduce256(x2, get256(&mut data, smask), k2048);
+        x1 = reduce256(x1, get256(&mut data, smask), k2048);
+        x0 = reduce256(x0, get256(&mut data, smask), k2048);
+    }
+
+    let k256 = _mm256_broadcastsi128_si256(_mm_set_epi64x(P::RK20 as i64, P::RK19 as i64));
+    let mut x = reduce256(x7, x6, k256);
+    x = reduce256(x, x5, k256);
+    x = reduce256(x, x4, k256);
+    x = reduce256(x, x3, k256);
+    x = reduce256(x, x2, k256);
+    x = reduce256(x, x1, k256);
+    x = reduce256(x, x0, k256);
+
+    while data.len() >= 32 {
+        x = reduce256(x, get256(&mut data, smask), k256);
+    }
+
+    // Reduce the two 128-bit lanes into one.
+    let y = reduce128(
+        _mm256_castsi256_si128(x),
+        _mm256_extracti128_si256(x, 1),
+        _mm_set_epi64x(P::RK02 as i64, P::RK01 as i64),
+    );
+
+    finish::<P>(y, data)
+}
+
+#[inline]
+#[target_feature(enable = "avx512f", enable = "vpclmulqdq")]
+unsafe fn reduce512(a: __m512i, b: __m512i, keys: __m512i) -> __m512i {
+    let t1 = _mm512_clmulepi64_epi128(a, keys, 0x00);
+    let t2 = _mm512_clmulepi64_epi128(a, keys, 0x11);
+    // b ^ t1 ^ t2
+    _mm512_ternarylogic_epi64(b, t1, t2, 0x96)
+}
+
+#[inline]
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+unsafe fn get512(data: &mut &[u8], smask: __m512i) -> __m512i {
+    let r = _mm512_shuffle_epi8(_mm512_loadu_si512(data.as_ptr() as *const _), smask);
+    *data = &data[64..];
+    r
+}
+
+#[inline]
+#[target_feature(enable = "avx2", enable = "vpclmulqdq")]
+unsafe fn reduce256(a: __m256i, b: __m256i, keys: __m256i) -> __m256i {
+    let t1 = _mm256_clmulepi64_epi128(a, keys, 0x00);
+    let t2 = _mm256_clmulepi64_epi128(a, keys, 0x11);
+    _mm256_xor_si256(_mm256_xor_si256(b, t1), t2)
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn get256(data: &mut &[u8], smask: __m256i) -> __m256i {
+    let r = _mm256_shuffle_epi8(_mm256_loadu_si256(data.as_ptr() as *const __m256i), smask);
+    *data = &data[32..];
+    r
+}
+
+#[cfg(test)]
+mod test {
+    use quickcheck_macros::quickcheck;
+
+    use super::WideKeys;
+    use crate::Byond;
+
+    /// Stretches `seed` into at least 320B of data, so the wide kernels run
+    /// even after offsetting.
+    fn sample(seed: &[u8], len: u16) -> Vec<u8> {
+        (0..320 + len as usize)
+            .map(|i| {
+                seed.get(i % seed.len().max(1))
+                    .map_or(i as u8, |&b| b ^ i as u8)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn keys_match_literals() {
+        assert_eq!(0x25ed_382b_0000_0000, Byond::RK2048_LO);
+        assert_eq!(0x6d2b_811a_0000_0000, Byond::RK2048_HI);
+    }
+
+    #[quickcheck]
+    fn avx512_against_baseline(init: u32, seed: Vec<u8>, len: u16, offset: usize) -> bool {
+        if !(is_x86_feature_detected!("avx512f")
+            && is_x86_feature_detected!("avx512bw")
+            && is_x86_feature_detected!("vpclmulqdq"))
+        {
+            return true;
+        }
+        let data = sample(&seed, len);
+        // simulate random alignments by offsetting the slice by up to 63 bytes
+        let data = &data[offset & 0x3F..];
+        let expected = crate::baseline::slice_by_16(init, data);
+        unsafe { super::calculate_avx512::<Byond>(init, data) == expected }
+    }
+
+    #[quickcheck]
+    fn avx2_against_baseline(init: u32, seed: Vec<u8>, len: u16, offset: usize) -> bool {
+        if !(is_x86_feature_detected!("avx2") && is_x86_feature_detected!("vpclmulqdq")) {
+            return true;
+        }
+        let data = sample(&seed, len);
+        // simulate random alignments by offsetting the slice by up to 31 bytes
+        let data = &data[offset & 0x1F..];
+        let expected = crate::baseline::slice_by_16(init, data);
+        unsafe { super::calculate_avx2::<Byond>(init, data) == expected }
+    }
+}
@@ -1,651 +1,128 @@
-pub(crate) const BYTE_TABLE: [u32; 256] = [
-    0x0000, 0x00af, 0x015e, 0x01f1, 0x02bc, 0x0213, 0x03e2, 0x034d, 0x0578, 0x05d7, 0x0426, 0x0489,
-    0x07c4, 0x076b, 0x069a, 0x0635, 0x0af0, 0x0a5f, 0x0bae, 0x0b01, 0x084c, 0x08e3, 0x0912, 0x09bd,
-    0x0f88, 0x0f27, 0x0ed6, 0x0e79, 0x0d34, 0x0d9b, 0x0c6a, 0x0cc5, 0x15e0, 0x154f, 0x14be, 0x1411,
-    0x175c, 0x17f3, 0x1602, 0x16ad, 0x1098, 0x1037, 0x11c6, 0x1169, 0x1224, 0x128b, 0x137a, 0x13d5,
-    0x1f10, 0x1fbf, 0x1e4e, 0x1ee1, 0x1dac, 0x1d03, 0x1cf2, 0x1c5d, 0x1a68, 0x1ac7, 0x1b36, 0x1b99,
-    0x18d4, 0x187b, 0x198a, 0x1925, 0x2bc0, 0x2b6f, 0x2a9e, 0x2a31, 0x297c, 0x29d3, 0x2822, 0x288d,
-    0x2eb8, 0x2e17, 0x2fe6, 0x2f49, 0x2c04, 0x2cab, 0x2d5a, 0x2df5, 0x2130, 0x219f, 0x206e, 0x20c1,
-    0x238c, 0x2323, 0x22d2, 0x227d, 0x2448, 0x24e7, 0x2516, 0x25b9, 0x26f4, 0x265b, 0x27aa, 0x2705,
-    0x3e20, 0x3e8f, 0x3f7e, 0x3fd1, 0x3c9c, 0x3c33, 0x3dc2, 0x3d6d, 0x3b58, 0x3bf7, 0x3a06, 0x3aa9,
-    0x39e4, 0x394b, 0x38ba, 0x3815, 0x34d0, 0x347f, 0x358e, 0x3521, 0x366c, 0x36c3, 0x3732, 0x379d,
-    0x31a8, 0x3107, 0x30f6, 0x3059, 0x3314, 0x33bb, 0x324a, 0x32e5, 0x5780, 0x572f, 0x56de, 0x5671,
-    0x553c, 0x5593, 0x5462, 0x54cd, 0x52f8, 0x5257, 0x53a6, 0x5309, 0x5044, 0x50eb, 0x511a, 0x51b5,
-    0x5d70, 0x5ddf, 0x5c2e, 0x5c81, 0x5fcc, 0x5f63, 0x5e92, 0x5e3d, 0x5808, 0x58a7, 0x5956, 0x59f9,
-    0x5ab4, 0x5a1b, 0x5bea, 0x5b45, 0x4260, 0x42cf, 0x433e, 0x4391, 0x40dc, 0x4073, 0x4182, 0x412d,
-    0x4718, 0x47b7, 0x4646, 0x46e9, 0x45a4, 0x450b, 0x44fa, 0x4455, 0x4890, 0x483f, 0x49ce, 0x4961,
-    0x4a2c, 0x4a83, 0x4b72, 0x4bdd, 0x4de8, 0x4d47, 0x4cb6, 0x4c19, 0x4f54, 0x4ffb, 0x4e0a, 0x4ea5,
-    0x7c40, 0x7cef, 0x7d1e, 0x7db1, 0x7efc, 0x7e53, 0x7fa2, 0x7f0d, 0x7938, 0x7997, 0x7866, 0x78c9,
-    0x7b84, 0x7b2b, 0x7ada, 0x7a75, 0x76b0, 0x761f, 0x77ee, 0x7741, 0x740c, 0x74a3, 0x7552, 0x75fd,
-    0x73c8, 0x7367, 0x7296, 0x7239, 0x7174, 0x71db, 0x702a, 0x7085, 0x69a0, 0x690f, 0x68fe, 0x6851,
-    0x6b1c, 0x6bb3, 0x6a42, 0x6aed, 0x6cd8, 0x6c77, 0x6d86, 0x6d29, 0x6e64, 0x6ecb, 0x6f3a, 0x6f95,
-    0x6350, 0x63ff, 0x620e, 0x62a1, 0x61ec, 0x6143, 0x60b2, 0x601d, 0x6628, 0x6687, 0x6776, 0x67d9,
-    0x6494, 0x643b, 0x65ca, 0x6565,
-];
+/// The CRC-32/BYOND polynomial P(x), without its x^32 term.
+pub(crate) const POLY: u32 = 0xaf;
 
-pub(crate) const WORD_TABLE: [[u32; 256]; 16] = [
-    [
-        0x00000000, 0xaf000000, 0x5e010000, 0xf1010000, 0xbc020000, 0x13020000, 0xe2030000,
-        0x4d030000, 0x78050000, 0xd7050000, 0x26040000, 0x89040000, 0xc4070000, 0x6b070000,
-        0x9a060000, 0x35060000, 0xf00a0000, 0x5f0a0000, 0xae0b0000, 0x010b0000, 0x4c080000,
-        0xe3080000, 0x12090000, 0xbd090000, 0x880f0000, 0x270f0000, 0xd60e0000, 0x790e0000,
-        0x340d0000, 0x9b0d0000, 0x6a0c0000, 0xc50c0000, 0xe0150000, 0x4f150000, 0xbe140000,
-        0x11140000, 0x5c170000, 0xf3170000, 0x02160000, 0xad160000, 0x98100000, 0x37100000,
-        0xc6110000, 0x69110000, 0x24120000, 0x8b120000, 0x7a130000, 0xd5130000, 0x101f0000,
-        0xbf1f0000, 0x4e1e0000, 0xe11e0000, 0xac1d0000, 0x031d0000, 0xf21c0000, 0x5d1c0000,
-        0x681a0000, 0xc71a0000, 0x361b0000, 0x991b0000, 0xd4180000, 0x7b180000, 0x8a190000,
-        0x25190000, 0xc02b0000, 0x6f2b0000, 0x9e2a0000, 0x312a0000, 0x7c290000, 0xd3290000,
-        0x22280000, 0x8d280000, 0xb82e0000, 0x172e0000, 0xe62f0000, 0x492f0000, 0x042c0000,
-        0xab2c0000, 0x5a2d0000, 0xf52d0000, 0x30210000, 0x9f210000, 0x6e200000, 0xc1200000,
-        0x8c230000, 0x23230000, 0xd2220000, 0x7d220000, 0x48240000, 0xe7240000, 0x16250000,
-        0xb9250000, 0xf4260000, 0x5b260000, 0xaa270000, 0x05270000, 0x203e0000, 0x8f3e0000,
-        0x7e3f0000, 0xd13f0000, 0x9c3c0000, 0x333c0000, 0xc23d0000, 0x6d3d0000, 0x583b0000,
-        0xf73b0000, 0x063a0000, 0xa93a0000, 0xe4390000, 0x4b390000, 0xba380000, 0x15380000,
-        0xd0340000, 0x7f340000, 0x8e350000, 0x21350000, 0x6c360000, 0xc3360000, 0x32370000,
-        0x9d370000, 0xa8310000, 0x07310000, 0xf6300000, 0x59300000, 0x14330000, 0xbb330000,
-        0x4a320000, 0xe5320000, 0x80570000, 0x2f570000, 0xde560000, 0x71560000, 0x3c550000,
-        0x93550000, 0x62540000, 0xcd540000, 0xf8520000, 0x57520000, 0xa6530000, 0x09530000,
-        0x44500000, 0xeb500000, 0x1a510000, 0xb5510000, 0x705d0000, 0xdf5d0000, 0x2e5c0000,
-        0x815c0000, 0xcc5f0000, 0x635f0000, 0x925e0000, 0x3d5e0000, 0x08580000, 0xa7580000,
-        0x56590000, 0xf9590000, 0xb45a0000, 0x1b5a0000, 0xea5b0000, 0x455b0000, 0x60420000,
-        0xcf420000, 0x3e430000, 0x91430000, 0xdc400000, 0x73400000, 0x82410000, 0x2d410000,
-        0x18470000, 0xb7470000, 0x46460000, 0xe9460000, 0xa4450000, 0x0b450000, 0xfa440000,
-        0x55440000, 0x90480000, 0x3f480000, 0xce490000, 0x61490000, 0x2c4a0000, 0x834a0000,
-        0x724b0000, 0xdd4b0000, 0xe84d0000, 0x474d0000, 0xb64c0000, 0x194c0000, 0x544f0000,
-        0xfb4f0000, 0x0a4e0000, 0xa54e0000, 0x407c0000, 0xef7c0000, 0x1e7d0000, 0xb17d0000,
-        0xfc7e0000, 0x537e0000, 0xa27f0000, 0x0d7f0000, 0x38790000, 0x97790000, 0x66780000,
-        0xc9780000, 0x847b0000, 0x2b7b0000, 0xda7a0000, 0x757a0000, 0xb0760000, 0x1f760000,
-        0xee770000, 0x41770000, 0x0c740000, 0xa3740000, 0x52750000, 0xfd750000, 0xc8730000,
-        0x67730000, 0x96720000, 0x39720000, 0x74710000, 0xdb710000, 0x2a700000, 0x85700000,
-        0xa0690000, 0x0f690000, 0xfe680000, 0x51680000, 0x1c6b0000, 0xb36b0000, 0x426a0000,
-        0xed6a0000, 0xd86c0000, 0x776c0000, 0x866d0000, 0x296d0000, 0x646e0000, 0xcb6e0000,
-        0x3a6f0000, 0x956f0000, 0x50630000, 0xff630000, 0x0e620000, 0xa1620000, 0xec610000,
-        0x43610000, 0xb2600000, 0x1d600000, 0x28660000, 0x87660000, 0x76670000, 0xd9670000,
-        0x94640000, 0x3b640000, 0xca650000, 0x65650000,
-    ],
-    [
-        0x00000000, 0x00af0000, 0x005e0100, 0x00f10100, 0x00bc0200, 0x00130200, 0x00e20300,
-        0x004d0300, 0x00780500, 0x00d70500, 0x00260400, 0x00890400, 0x00c40700, 0x006b0700,
-        0x009a0600, 0x00350600, 0x00f00a00, 0x005f0a00, 0x00ae0b00, 0x00010b00, 0x004c0800,
-        0x00e30800, 0x00120900, 0x00bd0900, 0x00880f00, 0x00270f00, 0x00d60e00, 0x00790e00,
-        0x00340d00, 0x009b0d00, 0x006a0c00, 0x00c50c00, 0x00e01500, 0x004f1500, 0x00be1400,
-        0x00111400, 0x005c1700, 0x00f31700, 0x00021600, 0x00ad1600, 0x00981000, 0x00371000,
-        0x00c61100, 0x00691100, 0x00241200, 0x008b1200, 0x007a1300, 0x00d51300, 0x00101f00,
-        0x00bf1f00, 0x004e1e00, 0x00e11e00, 0x00ac1d00, 0x00031d00, 0x00f21c00, 0x005d1c00,
-        0x00681a00, 0x00c71a00, 0x00361b00, 0x00991b00, 0x00d41800, 0x007b1800, 0x008a1900,
-        0x00251900, 0x00c02b00, 0x006f2b00, 0x009e2a00, 0x00312a00, 0x007c2900, 0x00d32900,
-        0x00222800, 0x008d2800, 0x00b82e00, 0x00172e00, 0x00e62f00, 0x00492f00, 0x00042c00,
-        0x00ab2c00, 0x005a2d00, 0x00f52d00, 0x00302100, 0x009f2100, 0x006e2000, 0x00c12000,
-        0x008c2300, 0x00232300, 0x00d22200, 0x007d2200, 0x00482400, 0x00e72400, 0x00162500,
-        0x00b92500, 0x00f42600, 0x005b2600, 0x00aa2700, 0x00052700, 0x00203e00, 0x008f3e00,
-        0x007e3f00, 0x00d13f00, 0x009c3c00, 0x00333c00, 0x00c23d00, 0x006d3d00, 0x00583b00,
-        0x00f73b00, 0x00063a00, 0x00a93a00, 0x00e43900, 0x004b3900, 0x00ba3800, 0x00153800,
-        0x00d03400, 0x007f3400, 0x008e3500, 0x00213500, 0x006c3600, 0x00c33600, 0x00323700,
-        0x009d3700, 0x00a83100, 0x00073100, 0x00f63000, 0x00593000, 0x00143300, 0x00bb3300,
-        0x004a3200, 0x00e53200, 0x00805700, 0x002f5700, 0x00de5600, 0x00715600, 0x003c5500,
-        0x00935500, 0x00625400, 0x00cd5400, 0x00f85200, 0x00575200, 0x00a65300, 0x00095300,
-        0x00445000, 0x00eb5000, 0x001a5100, 0x00b55100, 0x00705d00, 0x00df5d00, 0x002e5c00,
-        0x00815c00, 0x00cc5f00, 0x00635f00, 0x00925e00, 0x003d5e00, 0x00085800, 0x00a75800,
-        0x00565900, 0x00f95900, 0x00b45a00, 0x001b5a00, 0x00ea5b00, 0x00455b00, 0x00604200,
-        0x00cf4200, 0x003e4300, 0x00914300, 0x00dc4000, 0x00734000, 0x00824100, 0x002d4100,
-        0x00184700, 0x00b74700, 0x00464600, 0x00e94600, 0x00a44500, 0x000b4500, 0x00fa4400,
-        0x00554400, 0x00904800, 0x003f4800, 0x00ce4900, 0x00614900, 0x002c4a00, 0x00834a00,
-        0x00724b00, 0x00dd4b00, 0x00e84d00, 0x00474d00, 0x00b64c00, 0x00194c00, 0x00544f00,
-        0x00fb4f00, 0x000a4e00, 0x00a54e00, 0x00407c00, 0x00ef7c00, 0x001e7d00, 0x00b17d00,
-        0x00fc7e00, 0x00537e00, 0x00a27f00, 0x000d7f00, 0x00387900, 0x00977900, 0x00667800,
-        0x00c97800, 0x00847b00, 0x002b7b00, 0x00da7a00, 0x00757a00, 0x00b07600, 0x001f7600,
-        0x00ee7700, 0x00417700, 0x000c7400, 0x00a37400, 0x00527500, 0x00fd7500, 0x00c87300,
-        0x00677300, 0x00967200, 0x00397200, 0x00747100, 0x00db7100, 0x002a7000, 0x00857000,
-        0x00a06900, 0x000f6900, 0x00fe6800, 0x00516800, 0x001c6b00, 0x00b36b00, 0x00426a00,
-        0x00ed6a00, 0x00d86c00, 0x00776c00, 0x00866d00, 0x00296d00, 0x00646e00, 0x00cb6e00,
-        0x003a6f00, 0x00956f00, 0x00506300, 0x00ff6300, 0x000e6200, 0x00a16200, 0x00ec6100,
-        0x00436100, 0x00b26000, 0x001d6000, 0x00286600, 0x00876600, 0x00766700, 0x00d96700,
-        0x00946400, 0x003b6400, 0x00ca6500, 0x00656500,
-    ],
-    [
-        0x00000000, 0x0000af00, 0x00005e01, 0x0000f101, 0x0000bc02, 0x00001302, 0x0000e203,
-        0x00004d03, 0x00007805, 0x0000d705, 0x00002604, 0x00008904, 0x0000c407, 0x00006b07,
-        0x00009a06, 0x00003506, 0x0000f00a, 0x00005f0a, 0x0000ae0b, 0x0000010b, 0x00004c08,
-        0x0000e308, 0x00001209, 0x0000bd09, 0x0000880f, 0x0000270f, 0x0000d60e, 0x0000790e,
-        0x0000340d, 0x00009b0d, 0x00006a0c, 0x0000c50c, 0x0000e015, 0x00004f15, 0x0000be14,
-        0x00001114, 0x00005c17, 0x0000f317, 0x00000216, 0x0000ad16, 0x00009810, 0x00003710,
-        0x0000c611, 0x00006911, 0x00002412, 0x00008b12, 0x00007a13, 0x0000d513, 0x0000101f,
-        0x0000bf1f, 0x00004e1e, 0x0000e11e, 0x0000ac1d, 0x0000031d, 0x0000f21c, 0x00005d1c,
-        0x0000681a, 0x0000c71a, 0x0000361b, 0x0000991b, 0x0000d418, 0x00007b18, 0x00008a19,
-        0x00002519, 0x0000c02b, 0x00006f2b, 0x00009e2a, 0x0000312a, 0x00007c29, 0x0000d329,
-        0x00002228, 0x00008d28, 0x0000b82e, 0x0000172e, 0x0000e62f, 0x0000492f, 0x0000042c,
-        0x0000ab2c, 0x00005a2d, 0x0000f52d, 0x00003021, 0x00009f21, 0x00006e20, 0x0000c120,
-        0x00008c23, 0x00002323, 0x0000d222, 0x00007d22, 0x00004824, 0x0000e724, 0x00001625,
-        0x0000b925, 0x0000f426, 0x00005b26, 0x0000aa27, 0x00000527, 0x0000203e, 0x00008f3e,
-        0x00007e3f, 0x0000d13f, 0x00009c3c, 0x0000333c, 0x0000c23d, 0x00006d3d, 0x0000583b,
-        0x0000f73b, 0x0000063a, 0x0000a93a, 0x0000e439, 0x00004b39, 0x0000ba38, 0x00001538,
-        0x0000d034, 0x00007f34, 0x00008e35, 0x00002135, 0x00006c36, 0x0000c336, 0x00003237,
-        0x00009d37, 0x0000a831, 0x00000731, 0x0000f630, 0x00005930, 0x00001433, 0x0000bb33,
-        0x00004a32, 0x0000e532, 0x00008057, 0x00002f57, 0x0000de56, 0x00007156, 0x00003c55,
-        0x00009355, 0x00006254, 0x0000cd54, 0x0000f852, 0x00005752, 0x0000a653, 0x00000953,
-        0x00004450, 0x0000eb50, 0x00001a51, 0x0000b551, 0x0000705d, 0x0000df5d, 0x00002e5c,
-        0x0000815c, 0x0000cc5f, 0x0000635f, 0x0000925e, 0x00003d5e, 0x00000858, 0x0000a758,
-        0x00005659, 0x0000f959, 0x0000b45a, 0x00001b5a, 0x0000ea5b, 0x0000455b, 0x00006042,
-        0x0000cf42, 0x00003e43, 0x00009143, 0x0000dc40, 0x00007340, 0x00008241, 0x00002d41,
-        0x00001847, 0x0000b747, 0x00004646, 0x0000e946, 0x0000a445, 0x00000b45, 0x0000fa44,
-        0x00005544, 0x00009048, 0x00003f48, 0x0000ce49, 0x00006149, 0x00002c4a, 0x0000834a,
-        0x0000724b, 0x0000dd4b, 0x0000e84d, 0x0000474d, 0x0000b64c, 0x0000194c, 0x0000544f,
-        0x0000fb4f, 0x00000a4e, 0x0000a54e, 0x0000407c, 0x0000ef7c, 0x00001e7d, 0x0000b17d,
-        0x0000fc7e, 0x0000537e, 0x0000a27f, 0x00000d7f, 0x00003879, 0x00009779, 0x00006678,
-        0x0000c978, 0x0000847b, 0x00002b7b, 0x0000da7a, 0x0000757a, 0x0000b076, 0x00001f76,
-        0x0000ee77, 0x00004177, 0x00000c74, 0x0000a374, 0x00005275, 0x0000fd75, 0x0000c873,
-        0x00006773, 0x00009672, 0x00003972, 0x00007471, 0x0000db71, 0x00002a70, 0x00008570,
-        0x0000a069, 0x00000f69, 0x0000fe68, 0x00005168, 0x00001c6b, 0x0000b36b, 0x0000426a,
-        0x0000ed6a, 0x0000d86c, 0x0000776c, 0x0000866d, 0x0000296d, 0x0000646e, 0x0000cb6e,
-        0x00003a6f, 0x0000956f, 0x00005063, 0x0000ff63, 0x00000e62, 0x0000a162, 0x0000ec61,
-        0x00004361, 0x0000b260, 0x00001d60, 0x00002866, 0x00008766, 0x00007667, 0x0000d967,
-        0x00009464, 0x00003b64, 0x0000ca65, 0x00006565,
-    ],
-    [
-        0x00000000, 0x000000af, 0xaf00005e, 0xaf0000f1, 0x5e0100bc, 0x5e010013, 0xf10100e2,
-        0xf101004d, 0x13020078, 0x130200d7, 0xbc020026, 0xbc020089, 0x4d0300c4, 0x4d03006b,
-        0xe203009a, 0xe2030035, 0x260400f0, 0x2604005f, 0x890400ae, 0x89040001, 0x7805004c,
-        0x780500e3, 0xd7050012, 0xd70500bd, 0x35060088, 0x35060027, 0x9a0600d6, 0x9a060079,
-        0x6b070034, 0x6b07009b, 0xc407006a, 0xc40700c5, 0xe30800e0, 0xe308004f, 0x4c0800be,
-        0x4c080011, 0xbd09005c, 0xbd0900f3, 0x12090002, 0x120900ad, 0xf00a0098, 0xf00a0037,
-        0x5f0a00c6, 0x5f0a0069, 0xae0b0024, 0xae0b008b, 0x010b007a, 0x010b00d5, 0xc50c0010,
-        0xc50c00bf, 0x6a0c004e, 0x6a0c00e1, 0x9b0d00ac, 0x9b0d0003, 0x340d00f2, 0x340d005d,
-        0xd60e0068, 0xd60e00c7, 0x790e0036, 0x790e0099, 0x880f00d4, 0x880f007b, 0x270f008a,
-        0x270f0025, 0x691100c0, 0x6911006f, 0xc611009e, 0xc6110031, 0x3710007c, 0x371000d3,
-        0x98100022, 0x9810008d, 0x7a1300b8, 0x7a130017, 0xd51300e6, 0xd5130049, 0x24120004,
-        0x241200ab, 0x8b12005a, 0x8b1200f5, 0x4f150030, 0x4f15009f, 0xe015006e, 0xe01500c1,
-        0x1114008c, 0x11140023, 0xbe1400d2, 0xbe14007d, 0x5c170048, 0x5c1700e7, 0xf3170016,
-        0xf31700b9, 0x021600f4, 0x0216005b, 0xad1600aa, 0xad160005, 0x8a190020, 0x8a19008f,
-        0x2519007e, 0x251900d1, 0xd418009c, 0xd4180033, 0x7b1800c2, 0x7b18006d, 0x991b0058,
-        0x991b00f7, 0x361b0006, 0x361b00a9, 0xc71a00e4, 0xc71a004b, 0x681a00ba, 0x681a0015,
-        0xac1d00d0, 0xac1d007f, 0x031d008e, 0x031d0021, 0xf21c006c, 0xf21c00c3, 0x5d1c0032,
-        0x5d1c009d, 0xbf1f00a8, 0xbf1f0007, 0x101f00f6, 0x101f0059, 0xe11e0014, 0xe11e00bb,
-        0x4e1e004a, 0x4e1e00e5, 0x7d220080, 0x7d22002f, 0xd22200de, 0xd2220071, 0x2323003c,
-        0x23230093, 0x8c230062, 0x8c2300cd, 0x6e2000f8, 0x6e200057, 0xc12000a6, 0xc1200009,
-        0x30210044, 0x302100eb, 0x9f21001a, 0x9f2100b5, 0x5b260070, 0x5b2600df, 0xf426002e,
-        0xf4260081, 0x052700cc, 0x05270063, 0xaa270092, 0xaa27003d, 0x48240008, 0x482400a7,
-        0xe7240056, 0xe72400f9, 0x162500b4, 0x1625001b, 0xb92500ea, 0xb9250045, 0x9e2a0060,
-        0x9e2a00cf, 0x312a003e, 0x312a0091, 0xc02b00dc, 0xc02b0073, 0x6f2b0082, 0x6f2b002d,
-        0x8d280018, 0x8d2800b7, 0x22280046, 0x222800e9, 0xd32900a4, 0xd329000b, 0x7c2900fa,
-        0x7c290055, 0xb82e0090, 0xb82e003f, 0x172e00ce, 0x172e0061, 0xe62f002c, 0xe62f0083,
-        0x492f0072, 0x492f00dd, 0xab2c00e8, 0xab2c0047, 0x042c00b6, 0x042c0019, 0xf52d0054,
-        0xf52d00fb, 0x5a2d000a, 0x5a2d00a5, 0x14330040, 0x143300ef, 0xbb33001e, 0xbb3300b1,
-        0x4a3200fc, 0x4a320053, 0xe53200a2, 0xe532000d, 0x07310038, 0x07310097, 0xa8310066,
-        0xa83100c9, 0x59300084, 0x5930002b, 0xf63000da, 0xf6300075, 0x323700b0, 0x3237001f,
-        0x9d3700ee, 0x9d370041, 0x6c36000c, 0x6c3600a3, 0xc3360052, 0xc33600fd, 0x213500c8,
-        0x21350067, 0x8e350096, 0x8e350039, 0x7f340074, 0x7f3400db, 0xd034002a, 0xd0340085,
-        0xf73b00a0, 0xf73b000f, 0x583b00fe, 0x583b0051, 0xa93a001c, 0xa93a00b3, 0x063a0042,
-        0x063a00ed, 0xe43900d8, 0xe4390077, 0x4b390086, 0x4b390029, 0xba380064, 0xba3800cb,
-        0x1538003a, 0x15380095, 0xd13f0050, 0xd13f00ff, 0x7e3f000e, 0x7e3f00a1, 0x8f3e00ec,
-        0x8f3e0043, 0x203e00b2, 0x203e001d, 0xc23d0028, 0xc23d0087, 0x6d3d0076, 0x6d3d00d9,
-        0x9c3c0094, 0x9c3c003b, 0x333c00ca, 0x333c0065,
-    ],
-    [
-        0x00000000, 0x55440000, 0xaa880000, 0xffcc0000, 0x54110100, 0x01550100, 0xfe990100,
-        0xabdd0100, 0xa8220200, 0xfd660200, 0x02aa0200, 0x57ee0200, 0xfc330300, 0xa9770300,
-        0x56bb0300, 0x03ff0300, 0x50450400, 0x05010400, 0xfacd0400, 0xaf890400, 0x04540500,
-        0x51100500, 0xaedc0500, 0xfb980500, 0xf8670600, 0xad230600, 0x52ef0600, 0x07ab0600,
-        0xac760700, 0xf9320700, 0x06fe0700, 0x53ba0700, 0xa08a0800, 0xf5ce0800, 0x0a020800,
-        0x5f460800, 0xf49b0900, 0xa1df0900, 0x5e130900, 0x0b570900, 0x08a80a00, 0x5dec0a00,
-        0xa2200a00, 0xf7640a00, 0x5cb90b00, 0x09fd0b00, 0xf6310b00, 0xa3750b00, 0xf0cf0c00,
-        0xa58b0c00, 0x5a470c00, 0x0f030c00, 0xa4de0d00, 0xf19a0d00, 0x0e560d00, 0x5b120d00,
-        0x58ed0e00, 0x0da90e00, 0xf2650e00, 0xa7210e00, 0x0cfc0f00, 0x59b80f00, 0xa6740f00,
-        0xf3300f00, 0x40151100, 0x15511100, 0xea9d1100, 0xbfd91100, 0x14041000, 0x41401000,
-        0xbe8c1000, 0xebc81000, 0xe8371300, 0xbd731300, 0x42bf1300, 0x17fb1300, 0xbc261200,
-        0xe9621200, 0x16ae1200, 0x43ea1200, 0x10501500, 0x45141500, 0xbad81500, 0xef9c1500,
-        0x44411400, 0x11051400, 0xeec91400, 0xbb8d1400, 0xb8721700, 0xed361700, 0x12fa1700,
-        0x47be1700, 0xec631600, 0xb9271600, 0x46eb1600, 0x13af1600, 0xe09f1900, 0xb5db1900,
-        0x4a171900, 0x1f531900, 0xb48e1800, 0xe1ca1800, 0x1e061800, 0x4b421800, 0x48bd1b00,
-        0x1df91b00, 0xe2351b00, 0xb7711b00, 0x1cac1a00, 0x49e81a00, 0xb6241a00, 0xe3601a00,
-        0xb0da1d00, 0xe59e1d00, 0x1a521d00, 0x4f161d00, 0xe4cb1c00, 0xb18f1c00, 0x4e431c00,
-        0x1b071c00, 0x18f81f00, 0x4dbc1f00, 0xb2701f00, 0xe7341f00, 0x4ce91e00, 0x19ad1e00,
-        0xe6611e00, 0xb3251e00, 0x802a2200, 0xd56e2200, 0x2aa22200, 0x7fe62200, 0xd43b2300,
-        0x817f2300, 0x7eb32300, 0x2bf72300, 0x28082000, 0x7d4c2000, 0x82802000, 0xd7c42000,
-        0x7c192100, 0x295d2100, 0xd6912100, 0x83d52100, 0xd06f2600, 0x852b2600, 0x7ae72600,
-        0x2fa32600, 0x847e2700, 0xd13a2700, 0x2ef62700, 0x7bb22700, 0x784d2400, 0x2d092400,
-        0xd2c52400, 0x87812400, 0x2c5c2500, 0x79182500, 0x86d42500, 0xd3902500, 0x20a02a00,
-        0x75e42a00, 0x8a282a00, 0xdf6c2a00, 0x74b12b00, 0x21f52b00, 0xde392b00, 0x8b7d2b00,
-        0x88822800, 0xddc62800, 0x220a2800, 0x774e2800, 0xdc932900, 0x89d72900, 0x761b2900,
-        0x235f2900, 0x70e52e00, 0x25a12e00, 0xda6d2e00, 0x8f292e00, 0x24f42f00, 0x71b02f00,
-        0x8e7c2f00, 0xdb382f00, 0xd8c72c00, 0x8d832c00, 0x724f2c00, 0x270b2c00, 0x8cd62d00,
-        0xd9922d00, 0x265e2d00, 0x731a2d00, 0xc03f3300, 0x957b3300, 0x6ab73300, 0x3ff33300,
-        0x942e3200, 0xc16a3200, 0x3ea63200, 0x6be23200, 0x681d3100, 0x3d593100, 0xc2953100,
-        0x97d13100, 0x3c0c3000, 0x69483000, 0x96843000, 0xc3c03000, 0x907a3700, 0xc53e3700,
-        0x3af23700, 0x6fb63700, 0xc46b3600, 0x912f3600, 0x6ee33600, 0x3ba73600, 0x38583500,
-        0x6d1c3500, 0x92d03500, 0xc7943500, 0x6c493400, 0x390d3400, 0xc6c13400, 0x93853400,
-        0x60b53b00, 0x35f13b00, 0xca3d3b00, 0x9f793b00, 0x34a43a00, 0x61e03a00, 0x9e2c3a00,
-        0xcb683a00, 0xc8973900, 0x9dd33900, 0x621f3900, 0x375b3900, 0x9c863800, 0xc9c23800,
-        0x360e3800, 0x634a3800, 0x30f03f00, 0x65b43f00, 0x9a783f00, 0xcf3c3f00, 0x64e13e00,
-        0x31a53e00, 0xce693e00, 0x9b2d3e00, 0x98d23d00, 0xcd963d00, 0x325a3d00, 0x671e3d00,
-        0xccc33c00, 0x99873c00, 0x664b3c00, 0x330f3c00,
-    ],
-    [
-        0x00000000, 0x00554400, 0x00aa8800, 0x00ffcc00, 0x00541101, 0x00015501, 0x00fe9901,
-        0x00abdd01, 0x00a82202, 0x00fd6602, 0x0002aa02, 0x0057ee02, 0x00fc3303, 0x00a97703,
-        0x0056bb03, 0x0003ff03, 0x00504504, 0x00050104, 0x00facd04, 0x00af8904, 0x00045405,
-        0x00511005, 0x00aedc05, 0x00fb9805, 0x00f86706, 0x00ad2306, 0x0052ef06, 0x0007ab06,
-        0x00ac7607, 0x00f93207, 0x0006fe07, 0x0053ba07, 0x00a08a08, 0x00f5ce08, 0x000a0208,
-        0x005f4608, 0x00f49b09, 0x00a1df09, 0x005e1309, 0x000b5709, 0x0008a80a, 0x005dec0a,
-        0x00a2200a, 0x00f7640a, 0x005cb90b, 0x0009fd0b, 0x00f6310b, 0x00a3750b, 0x00f0cf0c,
-        0x00a58b0c, 0x005a470c, 0x000f030c, 0x00a4de0d, 0x00f19a0d, 0x000e560d, 0x005b120d,
-        0x0058ed0e, 0x000da90e, 0x00f2650e, 0x00a7210e, 0x000cfc0f, 0x0059b80f, 0x00a6740f,
-        0x00f3300f, 0x00401511, 0x00155111, 0x00ea9d11, 0x00bfd911, 0x00140410, 0x00414010,
-        0x00be8c10, 0x00ebc810, 0x00e83713, 0x00bd7313, 0x0042bf13, 0x0017fb13, 0x00bc2612,
-        0x00e96212, 0x0016ae12, 0x0043ea12, 0x00105015, 0x00451415, 0x00bad815, 0x00ef9c15,
-        0x00444114, 0x00110514, 0x00eec914, 0x00bb8d14, 0x00b87217, 0x00ed3617, 0x0012fa17,
-        0x0047be17, 0x00ec6316, 0x00b92716, 0x0046eb16, 0x0013af16, 0x00e09f19, 0x00b5db19,
-        0x004a1719, 0x001f5319, 0x00b48e18, 0x00e1ca18, 0x001e0618, 0x004b4218, 0x0048bd1b,
-        0x001df91b, 0x00e2351b, 0x00b7711b, 0x001cac1a, 0x0049e81a, 0x00b6241a, 0x00e3601a,
-        0x00b0da1d, 0x00e59e1d, 0x001a521d, 0x004f161d, 0x00e4cb1c, 0x00b18f1c, 0x004e431c,
-        0x001b071c, 0x0018f81f, 0x004dbc1f, 0x00b2701f, 0x00e7341f, 0x004ce91e, 0x0019ad1e,
-        0x00e6611e, 0x00b3251e, 0x00802a22, 0x00d56e22, 0x002aa222, 0x007fe622, 0x00d43b23,
-        0x00817f23, 0x007eb323, 0x002bf723, 0x00280820, 0x007d4c20, 0x00828020, 0x00d7c420,
-        0x007c1921, 0x00295d21, 0x00d69121, 0x0083d521, 0x00d06f26, 0x00852b26, 0x007ae726,
-        0x002fa326, 0x00847e27, 0x00d13a27, 0x002ef627, 0x007bb227, 0x00784d24, 0x002d0924,
-        0x00d2c524, 0x00878124, 0x002c5c25, 0x00791825, 0x0086d425, 0x00d39025, 0x0020a02a,
-        0x0075e42a, 0x008a282a, 0x00df6c2a, 0x0074b12b, 0x0021f52b, 0x00de392b, 0x008b7d2b,
-        0x00888228, 0x00ddc628, 0x00220a28, 0x00774e28, 0x00dc9329, 0x0089d729, 0x00761b29,
-        0x00235f29, 0x0070e52e, 0x0025a12e, 0x00da6d2e, 0x008f292e, 0x0024f42f, 0x0071b02f,
-        0x008e7c2f, 0x00db382f, 0x00d8c72c, 0x008d832c, 0x00724f2c, 0x00270b2c, 0x008cd62d,
-        0x00d9922d, 0x00265e2d, 0x00731a2d, 0x00c03f33, 0x00957b33, 0x006ab733, 0x003ff333,
-        0x00942e32, 0x00c16a32, 0x003ea632, 0x006be232, 0x00681d31, 0x003d5931, 0x00c29531,
-        0x0097d131, 0x003c0c30, 0x00694830, 0x00968430, 0x00c3c030, 0x00907a37, 0x00c53e37,
-        0x003af237, 0x006fb637, 0x00c46b36, 0x00912f36, 0x006ee336, 0x003ba736, 0x00385835,
-        0x006d1c35, 0x0092d035, 0x00c79435, 0x006c4934, 0x00390d34, 0x00c6c134, 0x00938534,
-        0x0060b53b, 0x0035f13b, 0x00ca3d3b, 0x009f793b, 0x0034a43a, 0x0061e03a, 0x009e2c3a,
-        0x00cb683a, 0x00c89739, 0x009dd339, 0x00621f39, 0x00375b39, 0x009c8638, 0x00c9c238,
-        0x00360e38, 0x00634a38, 0x0030f03f, 0x0065b43f, 0x009a783f, 0x00cf3c3f, 0x0064e13e,
-        0x0031a53e, 0x00ce693e, 0x009b2d3e, 0x0098d23d, 0x00cd963d, 0x00325a3d, 0x00671e3d,
-        0x00ccc33c, 0x0099873c, 0x00664b3c, 0x00330f3c,
-    ],
-    [
-        0x00000000, 0x00005544, 0x0000aa88, 0x0000ffcc, 0xaf005411, 0xaf000155, 0xaf00fe99,
-        0xaf00abdd, 0x5e01a822, 0x5e01fd66, 0x5e0102aa, 0x5e0157ee, 0xf101fc33, 0xf101a977,
-        0xf10156bb, 0xf10103ff, 0xbc025045, 0xbc020501, 0xbc02facd, 0xbc02af89, 0x13020454,
-        0x13025110, 0x1302aedc, 0x1302fb98, 0xe203f867, 0xe203ad23, 0xe20352ef, 0xe20307ab,
-        0x4d03ac76, 0x4d03f932, 0x4d0306fe, 0x4d0353ba, 0x7805a08a, 0x7805f5ce, 0x78050a02,
-        0x78055f46, 0xd705f49b, 0xd705a1df, 0xd7055e13, 0xd7050b57, 0x260408a8, 0x26045dec,
-        0x2604a220, 0x2604f764, 0x89045cb9, 0x890409fd, 0x8904f631, 0x8904a375, 0xc407f0cf,
-        0xc407a58b, 0xc4075a47, 0xc4070f03, 0x6b07a4de, 0x6b07f19a, 0x6b070e56, 0x6b075b12,
-        0x9a0658ed, 0x9a060da9, 0x9a06f265, 0x9a06a721, 0x35060cfc, 0x350659b8, 0x3506a674,
-        0x3506f330, 0x5f0a4015, 0x5f0a1551, 0x5f0aea9d, 0x5f0abfd9, 0xf00a1404, 0xf00a4140,
-        0xf00abe8c, 0xf00aebc8, 0x010be837, 0x010bbd73, 0x010b42bf, 0x010b17fb, 0xae0bbc26,
-        0xae0be962, 0xae0b16ae, 0xae0b43ea, 0xe3081050, 0xe3084514, 0xe308bad8, 0xe308ef9c,
-        0x4c084441, 0x4c081105, 0x4c08eec9, 0x4c08bb8d, 0xbd09b872, 0xbd09ed36, 0xbd0912fa,
-        0xbd0947be, 0x1209ec63, 0x1209b927, 0x120946eb, 0x120913af, 0x270fe09f, 0x270fb5db,
-        0x270f4a17, 0x270f1f53, 0x880fb48e, 0x880fe1ca, 0x880f1e06, 0x880f4b42, 0x790e48bd,
-        0x790e1df9, 0x790ee235, 0x790eb771, 0xd60e1cac, 0xd60e49e8, 0xd60eb624, 0xd60ee360,
-        0x9b0db0da, 0x9b0de59e, 0x9b0d1a52, 0x9b0d4f16, 0x340de4cb, 0x340db18f, 0x340d4e43,
-        0x340d1b07, 0xc50c18f8, 0xc50c4dbc, 0xc50cb270, 0xc50ce734, 0x6a0c4ce9, 0x6a0c19ad,
-        0x6a0ce661, 0x6a0cb325, 0xbe14802a, 0xbe14d56e, 0xbe142aa2, 0xbe147fe6, 0x1114d43b,
-        0x1114817f, 0x11147eb3, 0x11142bf7, 0xe0152808, 0xe0157d4c, 0xe0158280, 0xe015d7c4,
-        0x4f157c19, 0x4f15295d, 0x4f15d691, 0x4f1583d5, 0x0216d06f, 0x0216852b, 0x02167ae7,
-        0x02162fa3, 0xad16847e, 0xad16d13a, 0xad162ef6, 0xad167bb2, 0x5c17784d, 0x5c172d09,
-        0x5c17d2c5, 0x5c178781, 0xf3172c5c, 0xf3177918, 0xf31786d4, 0xf317d390, 0xc61120a0,
-        0xc61175e4, 0xc6118a28, 0xc611df6c, 0x691174b1, 0x691121f5, 0x6911de39, 0x69118b7d,
-        0x98108882, 0x9810ddc6, 0x9810220a, 0x9810774e, 0x3710dc93, 0x371089d7, 0x3710761b,
-        0x3710235f, 0x7a1370e5, 0x7a1325a1, 0x7a13da6d, 0x7a138f29, 0xd51324f4, 0xd51371b0,
-        0xd5138e7c, 0xd513db38, 0x2412d8c7, 0x24128d83, 0x2412724f, 0x2412270b, 0x8b128cd6,
-        0x8b12d992, 0x8b12265e, 0x8b12731a, 0xe11ec03f, 0xe11e957b, 0xe11e6ab7, 0xe11e3ff3,
-        0x4e1e942e, 0x4e1ec16a, 0x4e1e3ea6, 0x4e1e6be2, 0xbf1f681d, 0xbf1f3d59, 0xbf1fc295,
-        0xbf1f97d1, 0x101f3c0c, 0x101f6948, 0x101f9684, 0x101fc3c0, 0x5d1c907a, 0x5d1cc53e,
-        0x5d1c3af2, 0x5d1c6fb6, 0xf21cc46b, 0xf21c912f, 0xf21c6ee3, 0xf21c3ba7, 0x031d3858,
-        0x031d6d1c, 0x031d92d0, 0x031dc794, 0xac1d6c49, 0xac1d390d, 0xac1dc6c1, 0xac1d9385,
-        0x991b60b5, 0x991b35f1, 0x991bca3d, 0x991b9f79, 0x361b34a4, 0x361b61e0, 0x361b9e2c,
-        0x361bcb68, 0xc71ac897, 0xc71a9dd3, 0xc71a621f, 0xc71a375b, 0x681a9c86, 0x681ac9c2,
-        0x681a360e, 0x681a634a, 0x251930f0, 0x251965b4, 0x25199a78, 0x2519cf3c, 0x8a1964e1,
-        0x8a1931a5, 0x8a19ce69, 0x8a199b2d, 0x7b1898d2, 0x7b18cd96, 0x7b18325a, 0x7b18671e,
-        0xd418ccc3, 0xd4189987, 0xd418664b, 0xd418330f,
-    ],
-    [
-        0x00000000, 0x7c290055, 0xf85200aa, 0x847b00ff, 0x5fa50054, 0x238c0001, 0xa7f700fe,
-        0xdbde00ab, 0xbe4a01a8, 0xc26301fd, 0x46180102, 0x3a310157, 0xe1ef01fc, 0x9dc601a9,
-        0x19bd0156, 0x65940103, 0xd3950250, 0xafbc0205, 0x2bc702fa, 0x57ee02af, 0x8c300204,
-        0xf0190251, 0x746202ae, 0x084b02fb, 0x6ddf03f8, 0x11f603ad, 0x958d0352, 0xe9a40307,
-        0x327a03ac, 0x4e5303f9, 0xca280306, 0xb6010353, 0xa62b05a0, 0xda0205f5, 0x5e79050a,
-        0x2250055f, 0xf98e05f4, 0x85a705a1, 0x01dc055e, 0x7df5050b, 0x18610408, 0x6448045d,
-        0xe03304a2, 0x9c1a04f7, 0x47c4045c, 0x3bed0409, 0xbf9604f6, 0xc3bf04a3, 0x75be07f0,
-        0x099707a5, 0x8dec075a, 0xf1c5070f, 0x2a1b07a4, 0x563207f1, 0xd249070e, 0xae60075b,
-        0xcbf40658, 0xb7dd060d, 0x33a606f2, 0x4f8f06a7, 0x9451060c, 0xe8780659, 0x6c0306a6,
-        0x102a06f3, 0xe3570a40, 0x9f7e0a15, 0x1b050aea, 0x672c0abf, 0xbcf20a14, 0xc0db0a41,
-        0x44a00abe, 0x38890aeb, 0x5d1d0be8, 0x21340bbd, 0xa54f0b42, 0xd9660b17, 0x02b80bbc,
-        0x7e910be9, 0xfaea0b16, 0x86c30b43, 0x30c20810, 0x4ceb0845, 0xc89008ba, 0xb4b908ef,
-        0x6f670844, 0x134e0811, 0x973508ee, 0xeb1c08bb, 0x8e8809b8, 0xf2a109ed, 0x76da0912,
-        0x0af30947, 0xd12d09ec, 0xad0409b9, 0x297f0946, 0x55560913, 0x457c0fe0, 0x39550fb5,
-        0xbd2e0f4a, 0xc1070f1f, 0x1ad90fb4, 0x66f00fe1, 0xe28b0f1e, 0x9ea20f4b, 0xfb360e48,
-        0x871f0e1d, 0x03640ee2, 0x7f4d0eb7, 0xa4930e1c, 0xd8ba0e49, 0x5cc10eb6, 0x20e80ee3,
-        0x96e90db0, 0xeac00de5, 0x6ebb0d1a, 0x12920d4f, 0xc94c0de4, 0xb5650db1, 0x311e0d4e,
-        0x4d370d1b, 0x28a30c18, 0x548a0c4d, 0xd0f10cb2, 0xacd80ce7, 0x77060c4c, 0x0b2f0c19,
-        0x8f540ce6, 0xf37d0cb3, 0xc6af1480, 0xba8614d5, 0x3efd142a, 0x42d4147f, 0x990a14d4,
-        0xe5231481, 0x6158147e, 0x1d71142b, 0x78e51528, 0x04cc157d, 0x80b71582, 0xfc9e15d7,
-        0x2740157c, 0x5b691529, 0xdf1215d6, 0xa33b1583, 0x153a16d0, 0x69131685, 0xed68167a,
-        0x9141162f, 0x4a9f1684, 0x36b616d1, 0xb2cd162e, 0xcee4167b, 0xab701778, 0xd759172d,
-        0x532217d2, 0x2f0b1787, 0xf4d5172c, 0x88fc1779, 0x0c871786, 0x70ae17d3, 0x60841120,
-        0x1cad1175, 0x98d6118a, 0xe4ff11df, 0x3f211174, 0x43081121, 0xc77311de, 0xbb5a118b,
-        0xdece1088, 0xa2e710dd, 0x269c1022, 0x5ab51077, 0x816b10dc, 0xfd421089, 0x79391076,
-        0x05101023, 0xb3111370, 0xcf381325, 0x4b4313da, 0x376a138f, 0xecb41324, 0x909d1371,
-        0x14e6138e, 0x68cf13db, 0x0d5b12d8, 0x7172128d, 0xf5091272, 0x89201227, 0x52fe128c,
-        0x2ed712d9, 0xaaac1226, 0xd6851273, 0x25f81ec0, 0x59d11e95, 0xddaa1e6a, 0xa1831e3f,
-        0x7a5d1e94, 0x06741ec1, 0x820f1e3e, 0xfe261e6b, 0x9bb21f68, 0xe79b1f3d, 0x63e01fc2,
-        0x1fc91f97, 0xc4171f3c, 0xb83e1f69, 0x3c451f96, 0x406c1fc3, 0xf66d1c90, 0x8a441cc5,
-        0x0e3f1c3a, 0x72161c6f, 0xa9c81cc4, 0xd5e11c91, 0x519a1c6e, 0x2db31c3b, 0x48271d38,
-        0x340e1d6d, 0xb0751d92, 0xcc5c1dc7, 0x17821d6c, 0x6bab1d39, 0xefd01dc6, 0x93f91d93,
-        0x83d31b60, 0xfffa1b35, 0x7b811bca, 0x07a81b9f, 0xdc761b34, 0xa05f1b61, 0x24241b9e,
-        0x580d1bcb, 0x3d991ac8, 0x41b01a9d, 0xc5cb1a62, 0xb9e21a37, 0x623c1a9c, 0x1e151ac9,
-        0x9a6e1a36, 0xe6471a63, 0x50461930, 0x2c6f1965, 0xa814199a, 0xd43d19cf, 0x0fe31964,
-        0x73ca1931, 0xf7b119ce, 0x8b98199b, 0xee0c1898, 0x922518cd, 0x165e1832, 0x6a771867,
-        0xb1a918cc, 0xcd801899, 0x49fb1866, 0x35d21833,
-    ],
-    [
-        0x00000000, 0x235f2900, 0x46be5200, 0x65e17b00, 0x8c7ca500, 0xaf238c00, 0xcac2f700,
-        0xe99dde00, 0x18f94a01, 0x3ba66301, 0x5e471801, 0x7d183101, 0x9485ef01, 0xb7dac601,
-        0xd23bbd01, 0xf1649401, 0x30f29502, 0x13adbc02, 0x764cc702, 0x5513ee02, 0xbc8e3002,
-        0x9fd11902, 0xfa306202, 0xd96f4b02, 0x280bdf03, 0x0b54f603, 0x6eb58d03, 0x4deaa403,
-        0xa4777a03, 0x87285303, 0xe2c92803, 0xc1960103, 0x60e42b05, 0x43bb0205, 0x265a7905,
-        0x05055005, 0xec988e05, 0xcfc7a705, 0xaa26dc05, 0x8979f505, 0x781d6104, 0x5b424804,
-        0x3ea33304, 0x1dfc1a04, 0xf461c404, 0xd73eed04, 0xb2df9604, 0x9180bf04, 0x5016be07,
-        0x73499707, 0x16a8ec07, 0x35f7c507, 0xdc6a1b07, 0xff353207, 0x9ad44907, 0xb98b6007,
-        0x48eff406, 0x6bb0dd06, 0x0e51a606, 0x2d0e8f06, 0xc4935106, 0xe7cc7806, 0x822d0306,
-        0xa1722a06, 0xc0c8570a, 0xe3977e0a, 0x8676050a, 0xa5292c0a, 0x4cb4f20a, 0x6febdb0a,
-        0x0a0aa00a, 0x2955890a, 0xd8311d0b, 0xfb6e340b, 0x9e8f4f0b, 0xbdd0660b, 0x544db80b,
-        0x7712910b, 0x12f3ea0b, 0x31acc30b, 0xf03ac208, 0xd365eb08, 0xb6849008, 0x95dbb908,
-        0x7c466708, 0x5f194e08, 0x3af83508, 0x19a71c08, 0xe8c38809, 0xcb9ca109, 0xae7dda09,
-        0x8d22f309, 0x64bf2d09, 0x47e00409, 0x22017f09, 0x015e5609, 0xa02c7c0f, 0x8373550f,
-        0xe6922e0f, 0xc5cd070f, 0x2c50d90f, 0x0f0ff00f, 0x6aee8b0f, 0x49b1a20f, 0xb8d5360e,
-        0x9b8a1f0e, 0xfe6b640e, 0xdd344d0e, 0x34a9930e, 0x17f6ba0e, 0x7217c10e, 0x5148e80e,
-        0x90dee90d, 0xb381c00d, 0xd660bb0d, 0xf53f920d, 0x1ca24c0d, 0x3ffd650d, 0x5a1c1e0d,
-        0x7943370d, 0x8827a30c, 0xab788a0c, 0xce99f10c, 0xedc6d80c, 0x045b060c, 0x27042f0c,
-        0x42e5540c, 0x61ba7d0c, 0x8091af14, 0xa3ce8614, 0xc62ffd14, 0xe570d414, 0x0ced0a14,
-        0x2fb22314, 0x4a535814, 0x690c7114, 0x9868e515, 0xbb37cc15, 0xded6b715, 0xfd899e15,
-        0x14144015, 0x374b6915, 0x52aa1215, 0x71f53b15, 0xb0633a16, 0x933c1316, 0xf6dd6816,
-        0xd5824116, 0x3c1f9f16, 0x1f40b616, 0x7aa1cd16, 0x59fee416, 0xa89a7017, 0x8bc55917,
-        0xee242217, 0xcd7b0b17, 0x24e6d517, 0x07b9fc17, 0x62588717, 0x4107ae17, 0xe0758411,
-        0xc32aad11, 0xa6cbd611, 0x8594ff11, 0x6c092111, 0x4f560811, 0x2ab77311, 0x09e85a11,
-        0xf88cce10, 0xdbd3e710, 0xbe329c10, 0x9d6db510, 0x74f06b10, 0x57af4210, 0x324e3910,
-        0x11111010, 0xd0871113, 0xf3d83813, 0x96394313, 0xb5666a13, 0x5cfbb413, 0x7fa49d13,
-        0x1a45e613, 0x391acf13, 0xc87e5b12, 0xeb217212, 0x8ec00912, 0xad9f2012, 0x4402fe12,
-        0x675dd712, 0x02bcac12, 0x21e38512, 0x4059f81e, 0x6306d11e, 0x06e7aa1e, 0x25b8831e,
-        0xcc255d1e, 0xef7a741e, 0x8a9b0f1e, 0xa9c4261e, 0x58a0b21f, 0x7bff9b1f, 0x1e1ee01f,
-        0x3d41c91f, 0xd4dc171f, 0xf7833e1f, 0x9262451f, 0xb13d6c1f, 0x70ab6d1c, 0x53f4441c,
-        0x36153f1c, 0x154a161c, 0xfcd7c81c, 0xdf88e11c, 0xba699a1c, 0x9936b31c, 0x6852271d,
-        0x4b0d0e1d, 0x2eec751d, 0x0db35c1d, 0xe42e821d, 0xc771ab1d, 0xa290d01d, 0x81cff91d,
-        0x20bdd31b, 0x03e2fa1b, 0x6603811b, 0x455ca81b, 0xacc1761b, 0x8f9e5f1b, 0xea7f241b,
-        0xc9200d1b, 0x3844991a, 0x1b1bb01a, 0x7efacb1a, 0x5da5e21a, 0xb4383c1a, 0x9767151a,
-        0xf2866e1a, 0xd1d9471a, 0x104f4619, 0x33106f19, 0x56f11419, 0x75ae3d19, 0x9c33e319,
-        0xbf6cca19, 0xda8db119, 0xf9d29819, 0x08b60c18, 0x2be92518, 0x4e085e18, 0x6d577718,
-        0x84caa918, 0xa7958018, 0xc274fb18, 0xe12bd218,
-    ],
-    [
-        0x00000000, 0x00235f29, 0x0046be52, 0x0065e17b, 0x008c7ca5, 0x00af238c, 0x00cac2f7,
-        0x00e99dde, 0xaf18f94a, 0xaf3ba663, 0xaf5e4718, 0xaf7d1831, 0xaf9485ef, 0xafb7dac6,
-        0xafd23bbd, 0xaff16494, 0x5e31f295, 0x5e12adbc, 0x5e774cc7, 0x5e5413ee, 0x5ebd8e30,
-        0x5e9ed119, 0x5efb3062, 0x5ed86f4b, 0xf1290bdf, 0xf10a54f6, 0xf16fb58d, 0xf14ceaa4,
-        0xf1a5777a, 0xf1862853, 0xf1e3c928, 0xf1c09601, 0x1362e42b, 0x1341bb02, 0x13245a79,
-        0x13070550, 0x13ee988e, 0x13cdc7a7, 0x13a826dc, 0x138b79f5, 0xbc7a1d61, 0xbc594248,
-        0xbc3ca333, 0xbc1ffc1a, 0xbcf661c4, 0xbcd53eed, 0xbcb0df96, 0xbc9380bf, 0x4d5316be,
-        0x4d704997, 0x4d15a8ec, 0x4d36f7c5, 0x4ddf6a1b, 0x4dfc3532, 0x4d99d449, 0x4dba8b60,
-        0xe24beff4, 0xe268b0dd, 0xe20d51a6, 0xe22e0e8f, 0xe2c79351, 0xe2e4cc78, 0xe2812d03,
-        0xe2a2722a, 0x26c4c857, 0x26e7977e, 0x26827605, 0x26a1292c, 0x2648b4f2, 0x266bebdb,
-        0x260e0aa0, 0x262d5589, 0x89dc311d, 0x89ff6e34, 0x899a8f4f, 0x89b9d066, 0x89504db8,
-        0x89731291, 0x8916f3ea, 0x8935acc3, 0x78f53ac2, 0x78d665eb, 0x78b38490, 0x7890dbb9,
-        0x78794667, 0x785a194e, 0x783ff835, 0x781ca71c, 0xd7edc388, 0xd7ce9ca1, 0xd7ab7dda,
-        0xd78822f3, 0xd761bf2d, 0xd742e004, 0xd727017f, 0xd7045e56, 0x35a62c7c, 0x35857355,
-        0x35e0922e, 0x35c3cd07, 0x352a50d9, 0x35090ff0, 0x356cee8b, 0x354fb1a2, 0x9abed536,
-        0x9a9d8a1f, 0x9af86b64, 0x9adb344d, 0x9a32a993, 0x9a11f6ba, 0x9a7417c1, 0x9a5748e8,
-        0x6b97dee9, 0x6bb481c0, 0x6bd160bb, 0x6bf23f92, 0x6b1ba24c, 0x6b38fd65, 0x6b5d1c1e,
-        0x6b7e4337, 0xc48f27a3, 0xc4ac788a, 0xc4c999f1, 0xc4eac6d8, 0xc4035b06, 0xc420042f,
-        0xc445e554, 0xc466ba7d, 0x4c8891af, 0x4cabce86, 0x4cce2ffd, 0x4ced70d4, 0x4c04ed0a,
-        0x4c27b223, 0x4c425358, 0x4c610c71, 0xe39068e5, 0xe3b337cc, 0xe3d6d6b7, 0xe3f5899e,
-        0xe31c1440, 0xe33f4b69, 0xe35aaa12, 0xe379f53b, 0x12b9633a, 0x129a3c13, 0x12ffdd68,
-        0x12dc8241, 0x12351f9f, 0x121640b6, 0x1273a1cd, 0x1250fee4, 0xbda19a70, 0xbd82c559,
-        0xbde72422, 0xbdc47b0b, 0xbd2de6d5, 0xbd0eb9fc, 0xbd6b5887, 0xbd4807ae, 0x5fea7584,
-        0x5fc92aad, 0x5faccbd6, 0x5f8f94ff, 0x5f660921, 0x5f455608, 0x5f20b773, 0x5f03e85a,
-        0xf0f28cce, 0xf0d1d3e7, 0xf0b4329c, 0xf0976db5, 0xf07ef06b, 0xf05daf42, 0xf0384e39,
-        0xf01b1110, 0x01db8711, 0x01f8d838, 0x019d3943, 0x01be666a, 0x0157fbb4, 0x0174a49d,
-        0x011145e6, 0x01321acf, 0xaec37e5b, 0xaee02172, 0xae85c009, 0xaea69f20, 0xae4f02fe,
-        0xae6c5dd7, 0xae09bcac, 0xae2ae385, 0x6a4c59f8, 0x6a6f06d1, 0x6a0ae7aa, 0x6a29b883,
-        0x6ac0255d, 0x6ae37a74, 0x6a869b0f, 0x6aa5c426, 0xc554a0b2, 0xc577ff9b, 0xc5121ee0,
-        0xc53141c9, 0xc5d8dc17, 0xc5fb833e, 0xc59e6245, 0xc5bd3d6c, 0x347dab6d, 0x345ef444,
-        0x343b153f, 0x34184a16, 0x34f1d7c8, 0x34d288e1, 0x34b7699a, 0x349436b3, 0x9b655227,
-        0x9b460d0e, 0x9b23ec75, 0x9b00b35c, 0x9be92e82, 0x9bca71ab, 0x9baf90d0, 0x9b8ccff9,
-        0x792ebdd3, 0x790de2fa, 0x79680381, 0x794b5ca8, 0x79a2c176, 0x79819e5f, 0x79e47f24,
-        0x79c7200d, 0xd6364499, 0xd6151bb0, 0xd670facb, 0xd653a5e2, 0xd6ba383c, 0xd6996715,
-        0xd6fc866e, 0xd6dfd947, 0x271f4f46, 0x273c106f, 0x2759f114, 0x277aae3d, 0x279333e3,
-        0x27b06cca, 0x27d58db1, 0x27f6d298, 0x8807b60c, 0x8824e925, 0x8841085e, 0x88625777,
-        0x888bcaa9, 0x88a89580, 0x88cd74fb, 0x88ee2bd2,
-    ],
-    [
-        0x00000000, 0x3710235f, 0x6e2046be, 0x593065e1, 0x73408c7c, 0x4450af23, 0x1d60cac2,
-        0x2a70e99d, 0xe68018f9, 0xd1903ba6, 0x88a05e47, 0xbfb07d18, 0x95c09485, 0xa2d0b7da,
-        0xfbe0d23b, 0xccf0f164, 0x630131f2, 0x541112ad, 0x0d21774c, 0x3a315413, 0x1041bd8e,
-        0x27519ed1, 0x7e61fb30, 0x4971d86f, 0x8581290b, 0xb2910a54, 0xeba16fb5, 0xdcb14cea,
-        0xf6c1a577, 0xc1d18628, 0x98e1e3c9, 0xaff1c096, 0x690262e4, 0x5e1241bb, 0x0722245a,
-        0x30320705, 0x1a42ee98, 0x2d52cdc7, 0x7462a826, 0x43728b79, 0x8f827a1d, 0xb8925942,
-        0xe1a23ca3, 0xd6b21ffc, 0xfcc2f661, 0xcbd2d53e, 0x92e2b0df, 0xa5f29380, 0x0a035316,
-        0x3d137049, 0x642315a8, 0x533336f7, 0x7943df6a, 0x4e53fc35, 0x176399d4, 0x2073ba8b,
-        0xec834bef, 0xdb9368b0, 0x82a30d51, 0xb5b32e0e, 0x9fc3c793, 0xa8d3e4cc, 0xf1e3812d,
-        0xc6f3a272, 0x7d04c4c8, 0x4a14e797, 0x13248276, 0x2434a129, 0x0e4448b4, 0x39546beb,
-        0x60640e0a, 0x57742d55, 0x9b84dc31, 0xac94ff6e, 0xf5a49a8f, 0xc2b4b9d0, 0xe8c4504d,
-        0xdfd47312, 0x86e416f3, 0xb1f435ac, 0x1e05f53a, 0x2915d665, 0x7025b384, 0x473590db,
-        0x6d457946, 0x5a555a19, 0x03653ff8, 0x34751ca7, 0xf885edc3, 0xcf95ce9c, 0x96a5ab7d,
-        0xa1b58822, 0x8bc561bf, 0xbcd542e0, 0xe5e52701, 0xd2f5045e, 0x1406a62c, 0x23168573,
-        0x7a26e092, 0x4d36c3cd, 0x67462a50, 0x5056090f, 0x09666cee, 0x3e764fb1, 0xf286bed5,
-        0xc5969d8a, 0x9ca6f86b, 0xabb6db34, 0x81c632a9, 0xb6d611f6, 0xefe67417, 0xd8f65748,
-        0x770797de, 0x4017b481, 0x1927d160, 0x2e37f23f, 0x04471ba2, 0x335738fd, 0x6a675d1c,
-        0x5d777e43, 0x91878f27, 0xa697ac78, 0xffa7c999, 0xc8b7eac6, 0xe2c7035b, 0xd5d72004,
-        0x8ce745e5, 0xbbf766ba, 0x55088891, 0x6218abce, 0x3b28ce2f, 0x0c38ed70, 0x264804ed,
-        0x115827b2, 0x48684253, 0x7f78610c, 0xb3889068, 0x8498b337, 0xdda8d6d6, 0xeab8f589,
-        0xc0c81c14, 0xf7d83f4b, 0xaee85aaa, 0x99f879f5, 0x3609b963, 0x01199a3c, 0x5829ffdd,
-        0x6f39dc82, 0x4549351f, 0x72591640, 0x2b6973a1, 0x1c7950fe, 0xd089a19a, 0xe79982c5,
-        0xbea9e724, 0x89b9c47b, 0xa3c92de6, 0x94d90eb9, 0xcde96b58, 0xfaf94807, 0x3c0aea75,
-        0x0b1ac92a, 0x522aaccb, 0x653a8f94, 0x4f4a6609, 0x785a4556, 0x216a20b7, 0x167a03e8,
-        0xda8af28c, 0xed9ad1d3, 0xb4aab432, 0x83ba976d, 0xa9ca7ef0, 0x9eda5daf, 0xc7ea384e,
-        0xf0fa1b11, 0x5f0bdb87, 0x681bf8d8, 0x312b9d39, 0x063bbe66, 0x2c4b57fb, 0x1b5b74a4,
-        0x426b1145, 0x757b321a, 0xb98bc37e, 0x8e9be021, 0xd7ab85c0, 0xe0bba69f, 0xcacb4f02,
-        0xfddb6c5d, 0xa4eb09bc, 0x93fb2ae3, 0x280c4c59, 0x1f1c6f06, 0x462c0ae7, 0x713c29b8,
-        0x5b4cc025, 0x6c5ce37a, 0x356c869b, 0x027ca5c4, 0xce8c54a0, 0xf99c77ff, 0xa0ac121e,
-        0x97bc3141, 0xbdccd8dc, 0x8adcfb83, 0xd3ec9e62, 0xe4fcbd3d, 0x4b0d7dab, 0x7c1d5ef4,
-        0x252d3b15, 0x123d184a, 0x384df1d7, 0x0f5dd288, 0x566db769, 0x617d9436, 0xad8d6552,
-        0x9a9d460d, 0xc3ad23ec, 0xf4bd00b3, 0xdecde92e, 0xe9ddca71, 0xb0edaf90, 0x87fd8ccf,
-        0x410e2ebd, 0x761e0de2, 0x2f2e6803, 0x183e4b5c, 0x324ea2c1, 0x055e819e, 0x5c6ee47f,
-        0x6b7ec720, 0xa78e3644, 0x909e151b, 0xc9ae70fa, 0xfebe53a5, 0xd4ceba38, 0xe3de9967,
-        0xbaeefc86, 0x8dfedfd9, 0x220f1f4f, 0x151f3c10, 0x4c2f59f1, 0x7b3f7aae, 0x514f9333,
-        0x665fb06c, 0x3f6fd58d, 0x087ff6d2, 0xc48f07b6, 0xf39f24e9, 0xaaaf4108, 0x9dbf6257,
-        0xb7cf8bca, 0x80dfa895, 0xd9efcd74, 0xeeffee2b,
-    ],
-    [
-        0x00000000, 0x05101023, 0x0a202046, 0x0f303065, 0x1440408c, 0x115050af, 0x1e6060ca,
-        0x1b7070e9, 0x87808018, 0x8290903b, 0x8da0a05e, 0x88b0b07d, 0x93c0c094, 0x96d0d0b7,
-        0x99e0e0d2, 0x9cf0f0f1, 0x0e010131, 0x0b111112, 0x04212177, 0x01313154, 0x1a4141bd,
-        0x1f51519e, 0x106161fb, 0x157171d8, 0x89818129, 0x8c91910a, 0x83a1a16f, 0x86b1b14c,
-        0x9dc1c1a5, 0x98d1d186, 0x97e1e1e3, 0x92f1f1c0, 0x1c020262, 0x19121241, 0x16222224,
-        0x13323207, 0x084242ee, 0x0d5252cd, 0x026262a8, 0x0772728b, 0x9b82827a, 0x9e929259,
-        0x91a2a23c, 0x94b2b21f, 0x8fc2c2f6, 0x8ad2d2d5, 0x85e2e2b0, 0x80f2f293, 0x12030353,
-        0x17131370, 0x18232315, 0x1d333336, 0x064343df, 0x035353fc, 0x0c636399, 0x097373ba,
-        0x9583834b, 0x90939368, 0x9fa3a30d, 0x9ab3b32e, 0x81c3c3c7, 0x84d3d3e4, 0x8be3e381,
-        0x8ef3f3a2, 0x380404c4, 0x3d1414e7, 0x32242482, 0x373434a1, 0x2c444448, 0x2954546b,
-        0x2664640e, 0x2374742d, 0xbf8484dc, 0xba9494ff, 0xb5a4a49a, 0xb0b4b4b9, 0xabc4c450,
-        0xaed4d473, 0xa1e4e416, 0xa4f4f435, 0x360505f5, 0x331515d6, 0x3c2525b3, 0x39353590,
-        0x22454579, 0x2755555a, 0x2865653f, 0x2d75751c, 0xb18585ed, 0xb49595ce, 0xbba5a5ab,
-        0xbeb5b588, 0xa5c5c561, 0xa0d5d542, 0xafe5e527, 0xaaf5f504, 0x240606a6, 0x21161685,
-        0x2e2626e0, 0x2b3636c3, 0x3046462a, 0x35565609, 0x3a66666c, 0x3f76764f, 0xa38686be,
-        0xa696969d, 0xa9a6a6f8, 0xacb6b6db, 0xb7c6c632, 0xb2d6d611, 0xbde6e674, 0xb8f6f657,
-        0x2a070797, 0x2f1717b4, 0x202727d1, 0x253737f2, 0x3e47471b, 0x3b575738, 0x3467675d,
-        0x3177777e, 0xad87878f, 0xa89797ac, 0xa7a7a7c9, 0xa2b7b7ea, 0xb9c7c703, 0xbcd7d720,
-        0xb3e7e745, 0xb6f7f766, 0xdf080888, 0xda1818ab, 0xd52828ce, 0xd03838ed, 0xcb484804,
-        0xce585827, 0xc1686842, 0xc4787861, 0x58888890, 0x5d9898b3, 0x52a8a8d6, 0x57b8b8f5,
-        0x4cc8c81c, 0x49d8d83f, 0x46e8e85a, 0x43f8f879, 0xd10909b9, 0xd419199a, 0xdb2929ff,
-        0xde3939dc, 0xc5494935, 0xc0595916, 0xcf696973, 0xca797950, 0x568989a1, 0x53999982,
-        0x5ca9a9e7, 0x59b9b9c4, 0x42c9c92d, 0x47d9d90e, 0x48e9e96b, 0x4df9f948, 0xc30a0aea,
-        0xc61a1ac9, 0xc92a2aac, 0xcc3a3a8f, 0xd74a4a66, 0xd25a5a45, 0xdd6a6a20, 0xd87a7a03,
-        0x448a8af2, 0x419a9ad1, 0x4eaaaab4, 0x4bbaba97, 0x50caca7e, 0x55dada5d, 0x5aeaea38,
-        0x5ffafa1b, 0xcd0b0bdb, 0xc81b1bf8, 0xc72b2b9d, 0xc23b3bbe, 0xd94b4b57, 0xdc5b5b74,
-        0xd36b6b11, 0xd67b7b32, 0x4a8b8bc3, 0x4f9b9be0, 0x40abab85, 0x45bbbba6, 0x5ecbcb4f,
-        0x5bdbdb6c, 0x54ebeb09, 0x51fbfb2a, 0xe70c0c4c, 0xe21c1c6f, 0xed2c2c0a, 0xe83c3c29,
-        0xf34c4cc0, 0xf65c5ce3, 0xf96c6c86, 0xfc7c7ca5, 0x608c8c54, 0x659c9c77, 0x6aacac12,
-        0x6fbcbc31, 0x74ccccd8, 0x71dcdcfb, 0x7eecec9e, 0x7bfcfcbd, 0xe90d0d7d, 0xec1d1d5e,
-        0xe32d2d3b, 0xe63d3d18, 0xfd4d4df1, 0xf85d5dd2, 0xf76d6db7, 0xf27d7d94, 0x6e8d8d65,
-        0x6b9d9d46, 0x64adad23, 0x61bdbd00, 0x7acdcde9, 0x7fddddca, 0x70ededaf, 0x75fdfd8c,
-        0xfb0e0e2e, 0xfe1e1e0d, 0xf12e2e68, 0xf43e3e4b, 0xef4e4ea2, 0xea5e5e81, 0xe56e6ee4,
-        0xe07e7ec7, 0x7c8e8e36, 0x799e9e15, 0x76aeae70, 0x73bebe53, 0x68ceceba, 0x6ddede99,
-        0x62eeeefc, 0x67fefedf, 0xf50f0f1f, 0xf01f1f3c, 0xff2f2f59, 0xfa3f3f7a, 0xe14f4f93,
-        0xe45f5fb0, 0xeb6f6fd5, 0xee7f7ff6, 0x728f8f07, 0x779f9f24, 0x78afaf41, 0x7dbfbf62,
-        0x66cfcf8b, 0x63dfdfa8, 0x6cefefcd, 0x69ffffee,
-    ],
-    [
-        0x00000000, 0x11111010, 0x22222020, 0x33333030, 0x44444040, 0x55555050, 0x66666060,
-        0x77777070, 0x88888080, 0x99999090, 0xaaaaa0a0, 0xbbbbb0b0, 0xccccc0c0, 0xddddd0d0,
-        0xeeeee0e0, 0xfffff0f0, 0xbf110101, 0xae001111, 0x9d332121, 0x8c223131, 0xfb554141,
-        0xea445151, 0xd9776161, 0xc8667171, 0x37998181, 0x26889191, 0x15bba1a1, 0x04aab1b1,
-        0x73ddc1c1, 0x62ccd1d1, 0x51ffe1e1, 0x40eef1f1, 0x7e230202, 0x6f321212, 0x5c012222,
-        0x4d103232, 0x3a674242, 0x2b765252, 0x18456262, 0x09547272, 0xf6ab8282, 0xe7ba9292,
-        0xd489a2a2, 0xc598b2b2, 0xb2efc2c2, 0xa3fed2d2, 0x90cde2e2, 0x81dcf2f2, 0xc1320303,
-        0xd0231313, 0xe3102323, 0xf2013333, 0x85764343, 0x94675353, 0xa7546363, 0xb6457373,
-        0x49ba8383, 0x58ab9393, 0x6b98a3a3, 0x7a89b3b3, 0x0dfec3c3, 0x1cefd3d3, 0x2fdce3e3,
-        0x3ecdf3f3, 0xfc460404, 0xed571414, 0xde642424, 0xcf753434, 0xb8024444, 0xa9135454,
-        0x9a206464, 0x8b317474, 0x74ce8484, 0x65df9494, 0x56eca4a4, 0x47fdb4b4, 0x308ac4c4,
-        0x219bd4d4, 0x12a8e4e4, 0x03b9f4f4, 0x43570505, 0x52461515, 0x61752525, 0x70643535,
-        0x07134545, 0x16025555, 0x25316565, 0x34207575, 0xcbdf8585, 0xdace9595, 0xe9fda5a5,
-        0xf8ecb5b5, 0x8f9bc5c5, 0x9e8ad5d5, 0xadb9e5e5, 0xbca8f5f5, 0x82650606, 0x93741616,
-        0xa0472626, 0xb1563636, 0xc6214646, 0xd7305656, 0xe4036666, 0xf5127676, 0x0aed8686,
-        0x1bfc9696, 0x28cfa6a6, 0x39deb6b6, 0x4ea9c6c6, 0x5fb8d6d6, 0x6c8be6e6, 0x7d9af6f6,
-        0x3d740707, 0x2c651717, 0x1f562727, 0x0e473737, 0x79304747, 0x68215757, 0x5b126767,
-        0x4a037777, 0xb5fc8787, 0xa4ed9797, 0x97dea7a7, 0x86cfb7b7, 0xf1b8c7c7, 0xe0a9d7d7,
-        0xd39ae7e7, 0xc28bf7f7, 0xf88d0808, 0xe99c1818, 0xdaaf2828, 0xcbbe3838, 0xbcc94848,
-        0xadd85858, 0x9eeb6868, 0x8ffa7878, 0x70058888, 0x61149898, 0x5227a8a8, 0x4336b8b8,
-        0x3441c8c8, 0x2550d8d8, 0x1663e8e8, 0x0772f8f8, 0x479c0909, 0x568d1919, 0x65be2929,
-        0x74af3939, 0x03d84949, 0x12c95959, 0x21fa6969, 0x30eb7979, 0xcf148989, 0xde059999,
-        0xed36a9a9, 0xfc27b9b9, 0x8b50c9c9, 0x9a41d9d9, 0xa972e9e9, 0xb863f9f9, 0x86ae0a0a,
-        0x97bf1a1a, 0xa48c2a2a, 0xb59d3a3a, 0xc2ea4a4a, 0xd3fb5a5a, 0xe0c86a6a, 0xf1d97a7a,
-        0x0e268a8a, 0x1f379a9a, 0x2c04aaaa, 0x3d15baba, 0x4a62caca, 0x5b73dada, 0x6840eaea,
-        0x7951fafa, 0x39bf0b0b, 0x28ae1b1b, 0x1b9d2b2b, 0x0a8c3b3b, 0x7dfb4b4b, 0x6cea5b5b,
-        0x5fd96b6b, 0x4ec87b7b, 0xb1378b8b, 0xa0269b9b, 0x9315abab, 0x8204bbbb, 0xf573cbcb,
-        0xe462dbdb, 0xd751ebeb, 0xc640fbfb, 0x04cb0c0c, 0x15da1c1c, 0x26e92c2c, 0x37f83c3c,
-        0x408f4c4c, 0x519e5c5c, 0x62ad6c6c, 0x73bc7c7c, 0x8c438c8c, 0x9d529c9c, 0xae61acac,
-        0xbf70bcbc, 0xc807cccc, 0xd916dcdc, 0xea25ecec, 0xfb34fcfc, 0xbbda0d0d, 0xaacb1d1d,
-        0x99f82d2d, 0x88e93d3d, 0xff9e4d4d, 0xee8f5d5d, 0xddbc6d6d, 0xccad7d7d, 0x33528d8d,
-        0x22439d9d, 0x1170adad, 0x0061bdbd, 0x7716cdcd, 0x6607dddd, 0x5534eded, 0x4425fdfd,
-        0x7ae80e0e, 0x6bf91e1e, 0x58ca2e2e, 0x49db3e3e, 0x3eac4e4e, 0x2fbd5e5e, 0x1c8e6e6e,
-        0x0d9f7e7e, 0xf2608e8e, 0xe3719e9e, 0xd042aeae, 0xc153bebe, 0xb624cece, 0xa735dede,
-        0x9406eeee, 0x8517fefe, 0xc5f90f0f, 0xd4e81f1f, 0xe7db2f2f, 0xf6ca3f3f, 0x81bd4f4f,
-        0x90ac5f5f, 0xa39f6f6f, 0xb28e7f7f, 0x4d718f8f, 0x5c609f9f, 0x6f53afaf, 0x7e42bfbf,
-        0x0935cfcf, 0x1824dfdf, 0x2b17efef, 0x3a06ffff,
-    ],
-    [
-        0x00000000, 0xf01b1110, 0xe0372220, 0x102c3330, 0xc06f4440, 0x30745550, 0x20586660,
-        0xd0437770, 0x80df8880, 0x70c49990, 0x60e8aaa0, 0x90f3bbb0, 0x40b0ccc0, 0xb0abddd0,
-        0xa087eee0, 0x509cfff0, 0xafbf1101, 0x5fa40011, 0x4f883321, 0xbf932231, 0x6fd05541,
-        0x9fcb4451, 0x8fe77761, 0x7ffc6671, 0x2f609981, 0xdf7b8891, 0xcf57bba1, 0x3f4caab1,
-        0xef0fddc1, 0x1f14ccd1, 0x0f38ffe1, 0xff23eef1, 0x5e7f2302, 0xae643212, 0xbe480122,
-        0x4e531032, 0x9e106742, 0x6e0b7652, 0x7e274562, 0x8e3c5472, 0xdea0ab82, 0x2ebbba92,
-        0x3e9789a2, 0xce8c98b2, 0x1ecfefc2, 0xeed4fed2, 0xfef8cde2, 0x0ee3dcf2, 0xf1c03203,
-        0x01db2313, 0x11f71023, 0xe1ec0133, 0x31af7643, 0xc1b46753, 0xd1985463, 0x21834573,
-        0x711fba83, 0x8104ab93, 0x912898a3, 0x613389b3, 0xb170fec3, 0x416befd3, 0x5147dce3,
-        0xa15ccdf3, 0xbcfe4604, 0x4ce55714, 0x5cc96424, 0xacd27534, 0x7c910244, 0x8c8a1354,
-        0x9ca62064, 0x6cbd3174, 0x3c21ce84, 0xcc3adf94, 0xdc16eca4, 0x2c0dfdb4, 0xfc4e8ac4,
-        0x0c559bd4, 0x1c79a8e4, 0xec62b9f4, 0x13415705, 0xe35a4615, 0xf3767525, 0x036d6435,
-        0xd32e1345, 0x23350255, 0x33193165, 0xc3022075, 0x939edf85, 0x6385ce95, 0x73a9fda5,
-        0x83b2ecb5, 0x53f19bc5, 0xa3ea8ad5, 0xb3c6b9e5, 0x43dda8f5, 0xe2816506, 0x129a7416,
-        0x02b64726, 0xf2ad5636, 0x22ee2146, 0xd2f53056, 0xc2d90366, 0x32c21276, 0x625eed86,
-        0x9245fc96, 0x8269cfa6, 0x7272deb6, 0xa231a9c6, 0x522ab8d6, 0x42068be6, 0xb21d9af6,
-        0x4d3e7407, 0xbd256517, 0xad095627, 0x5d124737, 0x8d513047, 0x7d4a2157, 0x6d661267,
-        0x9d7d0377, 0xcde1fc87, 0x3dfaed97, 0x2dd6dea7, 0xddcdcfb7, 0x0d8eb8c7, 0xfd95a9d7,
-        0xedb99ae7, 0x1da28bf7, 0x78fd8d08, 0x88e69c18, 0x98caaf28, 0x68d1be38, 0xb892c948,
-        0x4889d858, 0x58a5eb68, 0xa8befa78, 0xf8220588, 0x08391498, 0x181527a8, 0xe80e36b8,
-        0x384d41c8, 0xc85650d8, 0xd87a63e8, 0x286172f8, 0xd7429c09, 0x27598d19, 0x3775be29,
-        0xc76eaf39, 0x172dd849, 0xe736c959, 0xf71afa69, 0x0701eb79, 0x579d1489, 0xa7860599,
-        0xb7aa36a9, 0x47b127b9, 0x97f250c9, 0x67e941d9, 0x77c572e9, 0x87de63f9, 0x2682ae0a,
-        0xd699bf1a, 0xc6b58c2a, 0x36ae9d3a, 0xe6edea4a, 0x16f6fb5a, 0x06dac86a, 0xf6c1d97a,
-        0xa65d268a, 0x5646379a, 0x466a04aa, 0xb67115ba, 0x663262ca, 0x962973da, 0x860540ea,
-        0x761e51fa, 0x893dbf0b, 0x7926ae1b, 0x690a9d2b, 0x99118c3b, 0x4952fb4b, 0xb949ea5b,
-        0xa965d96b, 0x597ec87b, 0x09e2378b, 0xf9f9269b, 0xe9d515ab, 0x19ce04bb, 0xc98d73cb,
-        0x399662db, 0x29ba51eb, 0xd9a140fb, 0xc403cb0c, 0x3418da1c, 0x2434e92c, 0xd42ff83c,
-        0x046c8f4c, 0xf4779e5c, 0xe45bad6c, 0x1440bc7c, 0x44dc438c, 0xb4c7529c, 0xa4eb61ac,
-        0x54f070bc, 0x84b307cc, 0x74a816dc, 0x648425ec, 0x949f34fc, 0x6bbcda0d, 0x9ba7cb1d,
-        0x8b8bf82d, 0x7b90e93d, 0xabd39e4d, 0x5bc88f5d, 0x4be4bc6d, 0xbbffad7d, 0xeb63528d,
-        0x1b78439d, 0x0b5470ad, 0xfb4f61bd, 0x2b0c16cd, 0xdb1707dd, 0xcb3b34ed, 0x3b2025fd,
-        0x9a7ce80e, 0x6a67f91e, 0x7a4bca2e, 0x8a50db3e, 0x5a13ac4e, 0xaa08bd5e, 0xba248e6e,
-        0x4a3f9f7e, 0x1aa3608e, 0xeab8719e, 0xfa9442ae, 0x0a8f53be, 0xdacc24ce, 0x2ad735de,
-        0x3afb06ee, 0xcae017fe, 0x35c3f90f, 0xc5d8e81f, 0xd5f4db2f, 0x25efca3f, 0xf5acbd4f,
-        0x05b7ac5f, 0x159b9f6f, 0xe5808e7f, 0xb51c718f, 0x4507609f, 0x552b53af, 0xa53042bf,
-        0x757335cf, 0x856824df, 0x954417ef, 0x655f06ff,
-    ],
-    [
-        0x00000000, 0xf0fa1b11, 0xe0f53722, 0x100f2c33, 0xc0eb6f44, 0x30117455, 0x201e5866,
-        0xd0e44377, 0x80d7df88, 0x702dc499, 0x6022e8aa, 0x90d8f3bb, 0x403cb0cc, 0xb0c6abdd,
-        0xa0c987ee, 0x50339cff, 0xafafbf11, 0x5f55a400, 0x4f5a8833, 0xbfa09322, 0x6f44d055,
-        0x9fbecb44, 0x8fb1e777, 0x7f4bfc66, 0x2f786099, 0xdf827b88, 0xcf8d57bb, 0x3f774caa,
-        0xef930fdd, 0x1f6914cc, 0x0f6638ff, 0xff9c23ee, 0x5e5f7f23, 0xaea56432, 0xbeaa4801,
-        0x4e505310, 0x9eb41067, 0x6e4e0b76, 0x7e412745, 0x8ebb3c54, 0xde88a0ab, 0x2e72bbba,
-        0x3e7d9789, 0xce878c98, 0x1e63cfef, 0xee99d4fe, 0xfe96f8cd, 0x0e6ce3dc, 0xf1f0c032,
-        0x010adb23, 0x1105f710, 0xe1ffec01, 0x311baf76, 0xc1e1b467, 0xd1ee9854, 0x21148345,
-        0x71271fba, 0x81dd04ab, 0x91d22898, 0x61283389, 0xb1cc70fe, 0x41366bef, 0x513947dc,
-        0xa1c35ccd, 0xbcbefe46, 0x4c44e557, 0x5c4bc964, 0xacb1d275, 0x7c559102, 0x8caf8a13,
-        0x9ca0a620, 0x6c5abd31, 0x3c6921ce, 0xcc933adf, 0xdc9c16ec, 0x2c660dfd, 0xfc824e8a,
-        0x0c78559b, 0x1c7779a8, 0xec8d62b9, 0x13114157, 0xe3eb5a46, 0xf3e47675, 0x031e6d64,
-        0xd3fa2e13, 0x23003502, 0x330f1931, 0xc3f50220, 0x93c69edf, 0x633c85ce, 0x7333a9fd,
-        0x83c9b2ec, 0x532df19b, 0xa3d7ea8a, 0xb3d8c6b9, 0x4322dda8, 0xe2e18165, 0x121b9a74,
-        0x0214b647, 0xf2eead56, 0x220aee21, 0xd2f0f530, 0xc2ffd903, 0x3205c212, 0x62365eed,
-        0x92cc45fc, 0x82c369cf, 0x723972de, 0xa2dd31a9, 0x52272ab8, 0x4228068b, 0xb2d21d9a,
-        0x4d4e3e74, 0xbdb42565, 0xadbb0956, 0x5d411247, 0x8da55130, 0x7d5f4a21, 0x6d506612,
-        0x9daa7d03, 0xcd99e1fc, 0x3d63faed, 0x2d6cd6de, 0xdd96cdcf, 0x0d728eb8, 0xfd8895a9,
-        0xed87b99a, 0x1d7da28b, 0x787dfd8d, 0x8887e69c, 0x9888caaf, 0x6872d1be, 0xb89692c9,
-        0x486c89d8, 0x5863a5eb, 0xa899befa, 0xf8aa2205, 0x08503914, 0x185f1527, 0xe8a50e36,
-        0x38414d41, 0xc8bb5650, 0xd8b47a63, 0x284e6172, 0xd7d2429c, 0x2728598d, 0x372775be,
-        0xc7dd6eaf, 0x17392dd8, 0xe7c336c9, 0xf7cc1afa, 0x073601eb, 0x57059d14, 0xa7ff8605,
-        0xb7f0aa36, 0x470ab127, 0x97eef250, 0x6714e941, 0x771bc572, 0x87e1de63, 0x262282ae,
-        0xd6d899bf, 0xc6d7b58c, 0x362dae9d, 0xe6c9edea, 0x1633f6fb, 0x063cdac8, 0xf6c6c1d9,
-        0xa6f55d26, 0x560f4637, 0x46006a04, 0xb6fa7115, 0x661e3262, 0x96e42973, 0x86eb0540,
-        0x76111e51, 0x898d3dbf, 0x797726ae, 0x69780a9d, 0x9982118c, 0x496652fb, 0xb99c49ea,
-        0xa99365d9, 0x59697ec8, 0x095ae237, 0xf9a0f926, 0xe9afd515, 0x1955ce04, 0xc9b18d73,
-        0x394b9662, 0x2944ba51, 0xd9bea140, 0xc4c303cb, 0x343918da, 0x243634e9, 0xd4cc2ff8,
-        0x04286c8f, 0xf4d2779e, 0xe4dd5bad, 0x142740bc, 0x4414dc43, 0xb4eec752, 0xa4e1eb61,
-        0x541bf070, 0x84ffb307, 0x7405a816, 0x640a8425, 0x94f09f34, 0x6b6cbcda, 0x9b96a7cb,
-        0x8b998bf8, 0x7b6390e9, 0xab87d39e, 0x5b7dc88f, 0x4b72e4bc, 0xbb88ffad, 0xebbb6352,
-        0x1b417843, 0x0b4e5470, 0xfbb44f61, 0x2b500c16, 0xdbaa1707, 0xcba53b34, 0x3b5f2025,
-        0x9a9c7ce8, 0x6a6667f9, 0x7a694bca, 0x8a9350db, 0x5a7713ac, 0xaa8d08bd, 0xba82248e,
-        0x4a783f9f, 0x1a4ba360, 0xeab1b871, 0xfabe9442, 0x0a448f53, 0xdaa0cc24, 0x2a5ad735,
-        0x3a55fb06, 0xcaafe017, 0x3533c3f9, 0xc5c9d8e8, 0xd5c6f4db, 0x253cefca, 0xf5d8acbd,
-        0x0522b7ac, 0x152d9b9f, 0xe5d7808e, 0xb5e41c71, 0x451e0760, 0x55112b53, 0xa5eb3042,
-        0x750f7335, 0x85f56824, 0x95fa4417, 0x65005f06,
-    ],
-    [
-        0x00000000, 0x5ffafa1b, 0xbef4f537, 0xe10e0f2c, 0x7ce9eb6f, 0x23131174, 0xc21d1e58,
-        0x9de7e443, 0xf8d2d7df, 0xa7282dc4, 0x462622e8, 0x19dcd8f3, 0x843b3cb0, 0xdbc1c6ab,
-        0x3acfc987, 0x6535339c, 0x5fa5afbf, 0x005f55a4, 0xe1515a88, 0xbeaba093, 0x234c44d0,
-        0x7cb6becb, 0x9db8b1e7, 0xc2424bfc, 0xa7777860, 0xf88d827b, 0x19838d57, 0x4679774c,
-        0xdb9e930f, 0x84646914, 0x656a6638, 0x3a909c23, 0x114a5f7f, 0x4eb0a564, 0xafbeaa48,
-        0xf0445053, 0x6da3b410, 0x32594e0b, 0xd3574127, 0x8cadbb3c, 0xe99888a0, 0xb66272bb,
-        0x576c7d97, 0x0896878c, 0x957163cf, 0xca8b99d4, 0x2b8596f8, 0x747f6ce3, 0x4eeff0c0,
-        0x11150adb, 0xf01b05f7, 0xafe1ffec, 0x32061baf, 0x6dfce1b4, 0x8cf2ee98, 0xd3081483,
-        0xb63d271f, 0xe9c7dd04, 0x08c9d228, 0x57332833, 0xcad4cc70, 0x952e366b, 0x74203947,
-        0x2bdac35c, 0x2294befe, 0x7d6e44e5, 0x9c604bc9, 0xc39ab1d2, 0x5e7d5591, 0x0187af8a,
-        0xe089a0a6, 0xbf735abd, 0xda466921, 0x85bc933a, 0x64b29c16, 0x3b48660d, 0xa6af824e,
-        0xf9557855, 0x185b7779, 0x47a18d62, 0x7d311141, 0x22cbeb5a, 0xc3c5e476, 0x9c3f1e6d,
-        0x01d8fa2e, 0x5e220035, 0xbf2c0f19, 0xe0d6f502, 0x85e3c69e, 0xda193c85, 0x3b1733a9,
-        0x64edc9b2, 0xf90a2df1, 0xa6f0d7ea, 0x47fed8c6, 0x180422dd, 0x33dee181, 0x6c241b9a,
-        0x8d2a14b6, 0xd2d0eead, 0x4f370aee, 0x10cdf0f5, 0xf1c3ffd9, 0xae3905c2, 0xcb0c365e,
-        0x94f6cc45, 0x75f8c369, 0x2a023972, 0xb7e5dd31, 0xe81f272a, 0x09112806, 0x56ebd21d,
-        0x6c7b4e3e, 0x3381b425, 0xd28fbb09, 0x8d754112, 0x1092a551, 0x4f685f4a, 0xae665066,
-        0xf19caa7d, 0x94a999e1, 0xcb5363fa, 0x2a5d6cd6, 0x75a796cd, 0xe840728e, 0xb7ba8895,
-        0x56b487b9, 0x094e7da2, 0xeb287dfd, 0xb4d287e6, 0x55dc88ca, 0x0a2672d1, 0x97c19692,
-        0xc83b6c89, 0x293563a5, 0x76cf99be, 0x13faaa22, 0x4c005039, 0xad0e5f15, 0xf2f4a50e,
-        0x6f13414d, 0x30e9bb56, 0xd1e7b47a, 0x8e1d4e61, 0xb48dd242, 0xeb772859, 0x0a792775,
-        0x5583dd6e, 0xc864392d, 0x979ec336, 0x7690cc1a, 0x296a3601, 0x4c5f059d, 0x13a5ff86,
-        0xf2abf0aa, 0xad510ab1, 0x30b6eef2, 0x6f4c14e9, 0x8e421bc5, 0xd1b8e1de, 0xfa622282,
-        0xa598d899, 0x4496d7b5, 0x1b6c2dae, 0x868bc9ed, 0xd97133f6, 0x387f3cda, 0x6785c6c1,
-        0x02b0f55d, 0x5d4a0f46, 0xbc44006a, 0xe3befa71, 0x7e591e32, 0x21a3e429, 0xc0adeb05,
-        0x9f57111e, 0xa5c78d3d, 0xfa3d7726, 0x1b33780a, 0x44c98211, 0xd92e6652, 0x86d49c49,
-        0x67da9365, 0x3820697e, 0x5d155ae2, 0x02efa0f9, 0xe3e1afd5, 0xbc1b55ce, 0x21fcb18d,
-        0x7e064b96, 0x9f0844ba, 0xc0f2bea1, 0xc9bcc303, 0x96463918, 0x77483634, 0x28b2cc2f,
-        0xb555286c, 0xeaafd277, 0x0ba1dd5b, 0x545b2740, 0x316e14dc, 0x6e94eec7, 0x8f9ae1eb,
-        0xd0601bf0, 0x4d87ffb3, 0x127d05a8, 0xf3730a84, 0xac89f09f, 0x96196cbc, 0xc9e396a7,
-        0x28ed998b, 0x77176390, 0xeaf087d3, 0xb50a7dc8, 0x540472e4, 0x0bfe88ff, 0x6ecbbb63,
-        0x31314178, 0xd03f4e54, 0x8fc5b44f, 0x1222500c, 0x4dd8aa17, 0xacd6a53b, 0xf32c5f20,
-        0xd8f69c7c, 0x870c6667, 0x6602694b, 0x39f89350, 0xa41f7713, 0xfbe58d08, 0x1aeb8224,
-        0x4511783f, 0x20244ba3, 0x7fdeb1b8, 0x9ed0be94, 0xc12a448f, 0x5ccda0cc, 0x03375ad7,
-        0xe23955fb, 0xbdc3afe0, 0x875333c3, 0xd8a9c9d8, 0x39a7c6f4, 0x665d3cef, 0xfbbad8ac,
-        0xa44022b7, 0x454e2d9b, 0x1ab4d780, 0x7f81e41c, 0x207b1e07, 0xc175112b, 0x9e8feb30,
-        0x03680f73, 0x5c92f568, 0xbd9cfa44, 0xe266005f,
-    ],
-];
+/// The checksum of each byte, with no initial value.
+pub(crate) const BYTE_TABLE: [u32; 256] = byte_table(POLY, false);
+
+/// Maps the low byte of each `BYTE_TABLE` entry back to its index.
+pub(crate) const BYTE_TABLE_INVERSE: [u8; 256] = byte_table_inverse();
+
+/// Generates the checksum of each byte for the polynomial `poly`, with no
+/// initial value. Reflected tables hold bit-reflected checksums.
+pub(crate) const fn byte_table(poly: u32, reflected: bool) -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = (i as u32) << 24;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x80000000 != 0 {
+                (crc << 1) ^ poly
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        if reflected {
+            table[(i as u8).reverse_bits() as usize] = crc.reverse_bits();
+        } else {
+            table[i] = crc;
+        }
+        i += 1;
+    }
+    table
+}
+
+const fn byte_table_inverse() -> [u8; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[(BYTE_TABLE[i] & 0xff) as usize] = i as u8;
+        i += 1;
+    }
+    table
+}
+
+/// Generates the slice-by-16 tables for the polynomial `poly`. Non-reflected
+/// tables are byte-swapped, so both kinds are indexed the same way.
+pub(crate) const fn word_table(poly: u32, reflected: bool) -> [[u32; 256]; 16] {
+    let bytes = byte_table(poly, reflected);
+    let mut table = [[0; 256]; 16];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = bytes[i];
+        let mut n = 0;
+        while n < 16 {
+            if reflected {
+                table[n][i] = crc;
+                crc = (crc >> 8) ^ bytes[(crc & 0xff) as usize];
+            } else {
+                table[n][i] = crc.swap_bytes();
+                crc = (crc << 8) ^ bytes[(crc >> 24) as usize];
+            }
+            n += 1;
+        }
+        i += 1;
+    }
+    table
+}
+
+/// x^n modulo P(x), for the polynomial `poly`.
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+    allow(dead_code)
+)]
+pub(crate) const fn xnmodp(poly: u32, mut n: u64) -> u32 {
+    let mut xp = 1u32;
+    while n != 0 {
+        xp = if xp & 0x80000000 != 0 {
+            (xp << 1) ^ poly
+        } else {
+            xp << 1
+        };
+        n -= 1;
+    }
+    xp
+}
+
+#[cfg(test)]
+mod literals;
+
+#[cfg(test)]
+mod tests {
+    use super::literals;
+    use crate::engine::{Byond, Tables};
+
+    #[test]
+    fn byte_table_matches_literals() {
+        assert_eq!(literals::BYTE_TABLE, super::BYTE_TABLE);
+    }
+
+    #[test]
+    fn byte_table_inverse_matches_literals() {
+        assert_eq!(literals::BYTE_TABLE_INVERSE, super::BYTE_TABLE_INVERSE);
+    }
+
+    #[test]
+    fn word_table_matches_literals() {
+        assert_eq!(literals::WORD_TABLE, <Byond as Tables>::WORD_TABLE);
+    }
+
+    #[test]
+    fn reflected_byte_table_is_valid() {
+        // Spot checks against the well-known zlib CRC-32 table.
+        let table = super::byte_table(0x04c11db7, true);
+        assert_eq!(0x77073096, table[1]);
+        assert_eq!(0x2d02ef8d, table[255]);
+    }
+
+    #[test]
+    fn xnmodp_matches_combine() {
+        for n in 0..64 {
+            assert_eq!(
+                crate::combine::x8nmodp(n),
+                super::xnmodp(super::POLY, 8 * n)
+            );
+        }
+    }
+}